@@ -0,0 +1,73 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use koblitz_curves::binary_field::clmul;
+use koblitz_curves::binary_field::polynomial::KARATSUBA_THRESHOLD;
+use koblitz_curves::prelude::*;
+use std::hint::black_box;
+
+fn bench_mul(c: &mut Criterion) {
+    let (x, y) = (
+        Fq233::from_hex_string("0x01ecfca5ace9b696238406aab3cf75090c2e7a4ae879be9f29bea5e704b6"),
+        Fq233::from_hex_string("0x00fe6d2c103ee8b9d0faed054a01c4684bb98dfe9f8abc8dc7b328e08071"),
+    );
+
+    let mut group = c.benchmark_group("Fq233 multiplication");
+    group.bench_function("window", |b| {
+        b.iter(|| Fq233::reduce(black_box(x.0).window_mul(&black_box(y.0))))
+    });
+    group.bench_function("karatsuba", |b| {
+        b.iter(|| {
+            let c = black_box(x.0).karatsuba_mul(&black_box(y.0), KARATSUBA_THRESHOLD);
+            Fq233::reduce(c)
+        })
+    });
+    group.bench_function("masked", |b| {
+        b.iter(|| Fq233::reduce(black_box(x.0).masked_mul(&black_box(y.0))))
+    });
+    if clmul::is_available() {
+        group.bench_function("pclmulqdq", |b| {
+            b.iter(|| Fq233::reduce(clmul::mul(&black_box(x.0), &black_box(y.0)).unwrap()))
+        });
+    }
+    group.finish();
+
+    let (x, y) = (Fq571::one() << 570, Fq571::one() << 300);
+    let (x, y) = (x.inv() + y, y.inv() + x);
+    let mut group = c.benchmark_group("Fq571 multiplication");
+    group.bench_function("window", |b| {
+        b.iter(|| Fq571::reduce(black_box(x.0).window_mul(&black_box(y.0))))
+    });
+    for threshold in [1, 2, 4, 6, 9, 18] {
+        group.bench_with_input(
+            BenchmarkId::new("karatsuba", threshold),
+            &threshold,
+            |b, threshold| {
+                b.iter(|| Fq571::reduce(black_box(x.0).karatsuba_mul(&black_box(y.0), *threshold)))
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_inv(c: &mut Criterion) {
+    let x =
+        Fq233::from_hex_string("0x01ecfca5ace9b696238406aab3cf75090c2e7a4ae879be9f29bea5e704b6");
+    // build the multi-squaring tables before measuring
+    let _ = x.inv_itoh_tsujii();
+
+    let mut group = c.benchmark_group("Fq233 inversion");
+    group.bench_function("euclidean", |b| b.iter(|| black_box(x).inv()));
+    group.bench_function("itoh_tsujii", |b| b.iter(|| black_box(x).inv_itoh_tsujii()));
+    for k in [1, 3, 7, 14, 116] {
+        group.bench_with_input(BenchmarkId::new("multi_square", k), &k, |b, k| {
+            b.iter(|| black_box(x).multi_square(*k))
+        });
+        // k squarings one by one, against which the tables of multi_square are measured
+        group.bench_with_input(BenchmarkId::new("squarings", k), &k, |b, k| {
+            b.iter(|| (0..*k).fold(black_box(x), |acc, _| acc.squaring()))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_mul, bench_inv);
+criterion_main!(benches);
@@ -0,0 +1,24 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use koblitz_curves::prelude::*;
+use std::hint::black_box;
+
+fn bench_scalar_mul(c: &mut Criterion) {
+    let g = K233Point::generator();
+    let k = Fn233::from_hex_string("0x7c14c59e6253fa1903f05141fd555fc81a3c974dd2258a62ec3be829a8");
+
+    let mut group = c.benchmark_group("K233 scalar multiplication");
+    group.bench_function("fast_montgomery_scalar_mul", |b| {
+        b.iter(|| K233::fast_montgomery_scalar_mul(black_box(&g), black_box(k)))
+    });
+    for w in 4..7 {
+        // precomputation is cached in the table, only the digit walk is measured
+        let table = TauNafwTable::new(&g, w);
+        group.bench_with_input(BenchmarkId::new("tau_naf_w_scalar_mul", w), &w, |b, _| {
+            b.iter(|| table.scalar_mul(black_box(k)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_scalar_mul);
+criterion_main!(benches);
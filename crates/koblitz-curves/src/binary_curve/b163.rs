@@ -0,0 +1,103 @@
+/// Instantiation of Binary Curve with B-163, E(\bar{K}): y^2 + xy = x^3 + x^2 + b, where \bar{K} = GF(2)[X] / X^163 + X^7 + X^6 + X^3 + 1
+/// b = 0x020a601907b8c953ca1481eb10512f78744a3205fd
+use super::curve::ProjectivePoint;
+use crate::binary_field::fq163::{self, Fq163, N};
+use crate::define_binary_curve;
+use crate::scalar_field::fnb163::FnB163;
+
+// base point of sect163r2 which generates the subgroup of prime order n
+define_binary_curve!(
+    B163, fq163::Fq163, FnB163,
+    name: "sect163r2",
+    oid: "1.3.132.0.15",
+    a2: "0x1",
+    a6: "0x020a601907b8c953ca1481eb10512f78744a3205fd",
+    generator_x: "0x03f0eba16286a2d57ea0991168d4994637e8343e36",
+    generator_y: "0x00d51fbc6c71a0094fa2cdd545b11c5c0c797324f1",
+    order: "0x40000000000000000000292fe77e70c12a4234c33",
+    cofactor: 2,
+);
+
+pub type B163Point = ProjectivePoint<N, Fq163, B163>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary_curve::curve::BinaryCurve;
+    use crate::binary_field::BinaryField;
+    use crate::scalar_field::ScalarField;
+
+    #[test]
+    fn test_is_on_curve() {
+        let g = B163::GENERATOR;
+        assert!(g.is_on_curve(), "Test for B163 is_on_curve failed!");
+        assert_eq!(B163::A6_SQRT.squaring(), B163::A6);
+    }
+
+    #[test]
+    fn test_generator_order() {
+        // [n - 1]G = -G, i.e. G generates the subgroup of prime order n
+        let g = B163::GENERATOR;
+        assert_eq!(
+            g * -FnB163::one(),
+            -g,
+            "Test for B163 generator order failed!"
+        );
+        assert_eq!(g * FnB163::zero(), B163::IDENTITY);
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let g = B163::GENERATOR;
+        let k = FnB163::from_hex_string("0x1237751aa4462ebfc5f915ef09cfbac6e7687a66e");
+        let expected = B163Point::from_affine(
+            Fq163::from_hex_string("0x0024d61d5d27a9e285a158b15d2e9e6b4c302ffb46"),
+            Fq163::from_hex_string("0x071950aaa5ef6f97c711f678fa1f3cf90b83c672e7"),
+        );
+        assert_eq!(
+            g * k,
+            expected,
+            "Test for B163 scalar multiplication failed!"
+        );
+        assert_eq!(
+            B163::montgomery_scalar_mul(&g, k),
+            expected,
+            "Test for B163 montgomery scalar multiplication failed!"
+        );
+    }
+
+    #[test]
+    fn test_addition() {
+        let (p, q, p_plus_q, p_double) = (
+            B163Point::from_affine(
+                Fq163::from_hex_string("0x0024d61d5d27a9e285a158b15d2e9e6b4c302ffb46"),
+                Fq163::from_hex_string("0x071950aaa5ef6f97c711f678fa1f3cf90b83c672e7"),
+            ),
+            B163Point::from_affine(
+                Fq163::from_hex_string("0x0143ad376663db7a20216e9c70d5bf6ce9c5a7711f"),
+                Fq163::from_hex_string("0x063caf3a9f5d3747cd2387afdff58bb8c194a25687"),
+            ),
+            B163Point::from_affine(
+                Fq163::from_hex_string("0x06aaf5aa54b073aee77b67176a220dfe5785e847c8"),
+                Fq163::from_hex_string("0x035b2c33502ad6461693e8c23143ac512e28119825"),
+            ),
+            B163Point::from_affine(
+                Fq163::from_hex_string("0x03a7a4f2655d7ca793e54b5f8299865b1ca1fa31f4"),
+                Fq163::from_hex_string("0x00330d40f7962aea274bdc80b9af8a25bdff02f79c"),
+            ),
+        );
+        // mixed addition and doubling
+        assert_eq!(p + q, p_plus_q, "Test for B163 mixed addition failed!");
+        assert_eq!(p + p, p_double, "Test for B163 doubling failed!");
+        // general addition with Z != 1 on both sides
+        let z = B163::GENERATOR.x;
+        let q_projective = B163Point::new(q.x * z, q.y * z.squaring(), z);
+        assert!(q_projective.is_on_curve() && !q_projective.is_affine());
+        assert_eq!(
+            B163::add(&(p + p), &q_projective),
+            p_double + q,
+            "Test for B163 projective addition failed!"
+        );
+        assert_eq!(p + q_projective + (-q), p);
+    }
+}
@@ -0,0 +1,129 @@
+/// Instantiation of Binary Curve with B-233, E(\bar{K}): y^2 + xy = x^3 + x^2 + b, where \bar{K} = GF(2)[X] / X^233 + X^74 + 1
+/// b = 0x0066647ede6c332c7f8c0923bb58213b333b20e9ce4281fe115f7d8f90ad
+use super::curve::ProjectivePoint;
+use crate::binary_field::fq233::{self, Fq233, N};
+use crate::define_binary_curve;
+use crate::scalar_field::fnb233::FnB233;
+
+// base point of sect233r1 which generates the subgroup of prime order n
+define_binary_curve!(
+    B233, fq233::Fq233, FnB233,
+    name: "sect233r1",
+    oid: "1.3.132.0.27",
+    a2: "0x1",
+    a6: "0x0066647ede6c332c7f8c0923bb58213b333b20e9ce4281fe115f7d8f90ad",
+    generator_x: "0x00fac9dfcbac8313bb2139f1bb755fef65bc391f8b36f8f8eb7371fd558b",
+    generator_y: "0x01006a08a41903350678e58528bebf8a0beff867a7ca36716f7e01f81052",
+    order: "0x1000000000000000000000000000013e974e72f8a6922031d2603cfe0d7",
+    cofactor: 2,
+);
+
+pub type B233Point = ProjectivePoint<N, Fq233, B233>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary_curve::curve::BinaryCurve;
+    use crate::binary_field::BinaryField;
+    use crate::scalar_field::ScalarField;
+
+    #[test]
+    fn test_is_on_curve() {
+        let g = B233::GENERATOR;
+        assert!(g.is_on_curve(), "Test for B233 is_on_curve failed!");
+        assert_eq!(B233::A6_SQRT.squaring(), B233::A6);
+    }
+
+    #[test]
+    fn test_generator_order() {
+        // [n - 1]G = -G, i.e. G generates the subgroup of prime order n
+        let g = B233::GENERATOR;
+        assert_eq!(
+            g * -FnB233::one(),
+            -g,
+            "Test for B233 generator order failed!"
+        );
+        assert_eq!(g * FnB233::zero(), B233::IDENTITY);
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let g = B233::GENERATOR;
+        let k =
+            FnB233::from_hex_string("0x72ac9abb0c3478442b4a8aa593eb40a9b81a070205e323bb2abf00188d");
+        let expected = B233Point::from_affine(
+            Fq233::from_hex_string(
+                "0x00610f8be50ef1c9aab58478cbc9b497efca95db79d67ea78d72fc481902",
+            ),
+            Fq233::from_hex_string(
+                "0x01f1a3b347bb4443d101b2086dc6c050be3e2bcc37fe1f754798e0cd6d44",
+            ),
+        );
+        assert_eq!(
+            g * k,
+            expected,
+            "Test for B233 scalar multiplication failed!"
+        );
+        assert_eq!(
+            B233::montgomery_scalar_mul(&g, k),
+            expected,
+            "Test for B233 montgomery scalar multiplication failed!"
+        );
+        // (0, sqrt(b)) has order 2, and x(P) = 0 can not go through the x-only ladder
+        let t = B233Point::from_affine(Fq233::zero(), B233::A6_SQRT);
+        assert!(t.is_on_curve());
+        assert_eq!(t * k, t, "Test for B233 scalar multiplication failed!");
+        assert!((t * (k + FnB233::one())).is_identity());
+    }
+
+    #[test]
+    fn test_addition() {
+        let (p, q, p_plus_q, p_double) = (
+            B233Point::from_affine(
+                Fq233::from_hex_string(
+                    "0x00610f8be50ef1c9aab58478cbc9b497efca95db79d67ea78d72fc481902",
+                ),
+                Fq233::from_hex_string(
+                    "0x01f1a3b347bb4443d101b2086dc6c050be3e2bcc37fe1f754798e0cd6d44",
+                ),
+            ),
+            B233Point::from_affine(
+                Fq233::from_hex_string(
+                    "0x0198b7efbeefdd93acb5341ddc83a3be48c5227ad043d623ac87fa8f283b",
+                ),
+                Fq233::from_hex_string(
+                    "0x006c0b191d1b3cac7d4f7025afd6b7ef0f04aa48f81e3569d1177f2d45fa",
+                ),
+            ),
+            B233Point::from_affine(
+                Fq233::from_hex_string(
+                    "0x0056c302f6b2fdfed59555934c0d958256becdf584e5c5ecbc84b7e4327f",
+                ),
+                Fq233::from_hex_string(
+                    "0x0184eefccfba0d45a28a1cd07531b6eb5896540fb8ecd4f003281721d66d",
+                ),
+            ),
+            B233Point::from_affine(
+                Fq233::from_hex_string(
+                    "0x003417d1c8414dd288f23bd567ec74aec8035b403b52b4d585a735325be3",
+                ),
+                Fq233::from_hex_string(
+                    "0x00b51197e93c296787f73cbb4a725786a5c158aee334188a1fcfee3e5b00",
+                ),
+            ),
+        );
+        // mixed addition and doubling
+        assert_eq!(p + q, p_plus_q, "Test for B233 mixed addition failed!");
+        assert_eq!(p + p, p_double, "Test for B233 doubling failed!");
+        // general addition with Z != 1 on both sides
+        let z = B233::GENERATOR.x;
+        let q_projective = B233Point::new(q.x * z, q.y * z.squaring(), z);
+        assert!(q_projective.is_on_curve() && !q_projective.is_affine());
+        assert_eq!(
+            B233::add(&(p + p), &q_projective),
+            p_double + q,
+            "Test for B233 projective addition failed!"
+        );
+        assert_eq!(p + q_projective + (-q), p);
+    }
+}
@@ -0,0 +1,125 @@
+/// Instantiation of Binary Curve with B-283, E(\bar{K}): y^2 + xy = x^3 + x^2 + b, where \bar{K} = GF(2)[X] / X^283 + X^12 + X^7 + X^5 + 1
+/// b = 0x027b680ac8b8596da5a4af8a19a0303fca97fd7645309fa2a581485af6263e313b79a2f5
+use super::curve::ProjectivePoint;
+use crate::binary_field::fq283::{self, Fq283, N};
+use crate::define_binary_curve;
+use crate::scalar_field::fnb283::FnB283;
+
+// base point of sect283r1 which generates the subgroup of prime order n
+define_binary_curve!(
+    B283, fq283::Fq283, FnB283,
+    name: "sect283r1",
+    oid: "1.3.132.0.17",
+    a2: "0x1",
+    a6: "0x027b680ac8b8596da5a4af8a19a0303fca97fd7645309fa2a581485af6263e313b79a2f5",
+    generator_x: "0x05f939258db7dd90e1934f8c70b0dfec2eed25b8557eac9c80e2e198f8cdbecd86b12053",
+    generator_y: "0x03676854fe24141cb98fe6d4b20d02b4516ff702350eddb0826779c813f0df45be8112f4",
+    order: "0x3ffffffffffffffffffffffffffffffffffef90399660fc938a90165b042a7cefadb307",
+    cofactor: 2,
+);
+
+pub type B283Point = ProjectivePoint<N, Fq283, B283>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary_curve::curve::BinaryCurve;
+    use crate::binary_field::BinaryField;
+    use crate::scalar_field::ScalarField;
+
+    #[test]
+    fn test_is_on_curve() {
+        let g = B283::GENERATOR;
+        assert!(g.is_on_curve(), "Test for B283 is_on_curve failed!");
+        assert_eq!(B283::A6_SQRT.squaring(), B283::A6);
+    }
+
+    #[test]
+    fn test_generator_order() {
+        // [n - 1]G = -G, i.e. G generates the subgroup of prime order n
+        let g = B283::GENERATOR;
+        assert_eq!(
+            g * -FnB283::one(),
+            -g,
+            "Test for B283 generator order failed!"
+        );
+        assert_eq!(g * FnB283::zero(), B283::IDENTITY);
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let g = B283::GENERATOR;
+        let k = FnB283::from_hex_string(
+            "0x271cb642967c63d3c9e0381e7f8ec98f36face167019017b4999178de6081741f0fd9e9",
+        );
+        let expected = B283Point::from_affine(
+            Fq283::from_hex_string(
+                "0x0738ca1a2821a9a614c8e3602279aff5f90a56905c9fcf3fb507706674cbcb352084f5ec",
+            ),
+            Fq283::from_hex_string(
+                "0x05226303983eedc127f750229e1c6b8a7372dfc121fa882c1cf1ecdff4aa553652831400",
+            ),
+        );
+        assert_eq!(
+            g * k,
+            expected,
+            "Test for B283 scalar multiplication failed!"
+        );
+        assert_eq!(
+            B283::montgomery_scalar_mul(&g, k),
+            expected,
+            "Test for B283 montgomery scalar multiplication failed!"
+        );
+    }
+
+    #[test]
+    fn test_addition() {
+        let (p, q, p_plus_q, p_double) = (
+            B283Point::from_affine(
+                Fq283::from_hex_string(
+                    "0x0738ca1a2821a9a614c8e3602279aff5f90a56905c9fcf3fb507706674cbcb352084f5ec",
+                ),
+                Fq283::from_hex_string(
+                    "0x05226303983eedc127f750229e1c6b8a7372dfc121fa882c1cf1ecdff4aa553652831400",
+                ),
+            ),
+            B283Point::from_affine(
+                Fq283::from_hex_string(
+                    "0x00fd4660839ac5cfbfeeb372e7960b436273403bf6a99928f137045920ab25218c033673",
+                ),
+                Fq283::from_hex_string(
+                    "0x078404a413b20df0c4e1369289f3a87ffaefb11fd938e99d74eac508655ddddcfcb31916",
+                ),
+            ),
+            B283Point::from_affine(
+                Fq283::from_hex_string(
+                    "0x07c5672179b0c5aae0a7934c13c273cc7448e0692d3bfde40e5351b946c4cc22a7cad40b",
+                ),
+                Fq283::from_hex_string(
+                    "0x048de0945985c9eee678ad1b5dd7e713b67223ff94924f540f8565210a996dcf04bf7b15",
+                ),
+            ),
+            B283Point::from_affine(
+                Fq283::from_hex_string(
+                    "0x01a918ddd6814893800411dd0a434ae2656d63cda67c3f5d7b4af30f42ee9a9b409fa56b",
+                ),
+                Fq283::from_hex_string(
+                    "0x062246df6cad5bf92afe05e68202a34bd83fb0104a4609113290f9006b02033e4efcfd7f",
+                ),
+            ),
+        );
+        // mixed addition and doubling
+        assert_eq!(p + q, p_plus_q, "Test for B283 mixed addition failed!");
+        assert_eq!(p + p, p_double, "Test for B283 doubling failed!");
+        // general addition with Z != 1 on both sides
+        let z = B283::GENERATOR.x;
+        let q_projective = B283Point::new(q.x * z, q.y * z.squaring(), z);
+        assert!(q_projective.is_on_curve() && !q_projective.is_affine());
+        assert_eq!(
+            B283::add(&(p + p), &q_projective),
+            p_double + q,
+            "Test for B283 projective addition failed!"
+        );
+        assert_eq!(p + q_projective + (-q), p);
+    }
+}
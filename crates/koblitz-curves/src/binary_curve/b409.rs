@@ -0,0 +1,125 @@
+/// Instantiation of Binary Curve with B-409, E(\bar{K}): y^2 + xy = x^3 + x^2 + b, where \bar{K} = GF(2)[X] / X^409 + X^87 + 1
+/// b = 0x0021a5c2c8ee9feb5c4b9a753b7b476b7fd6422ef1f3dd674761fa99d6ac27c8a9a197b272822f6cd57a55aa4f50ae317b13545f
+use super::curve::ProjectivePoint;
+use crate::binary_field::fq409::{self, Fq409, N};
+use crate::define_binary_curve;
+use crate::scalar_field::fnb409::FnB409;
+
+// base point of sect409r1 which generates the subgroup of prime order n
+define_binary_curve!(
+    B409, fq409::Fq409, FnB409,
+    name: "sect409r1",
+    oid: "1.3.132.0.37",
+    a2: "0x1",
+    a6: "0x0021a5c2c8ee9feb5c4b9a753b7b476b7fd6422ef1f3dd674761fa99d6ac27c8a9a197b272822f6cd57a55aa4f50ae317b13545f",
+    generator_x: "0x015d4860d088ddb3496b0c6064756260441cde4af1771d4db01ffe5b34e59703dc255a868a1180515603aeab60794e54bb7996a7",
+    generator_y: "0x0061b1cfab6be5f32bbfa78324ed106a7636b9c5a7bd198d0158aa4f5488d08f38514f1fdf4b4f40d2181b3681c364ba0273c706",
+    order: "0x10000000000000000000000000000000000000000000000000001e2aad6a612f33307be5fa47c3c9e052f838164cd37d9a21173",
+    cofactor: 2,
+);
+
+pub type B409Point = ProjectivePoint<N, Fq409, B409>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary_curve::curve::BinaryCurve;
+    use crate::binary_field::BinaryField;
+    use crate::scalar_field::ScalarField;
+
+    #[test]
+    fn test_is_on_curve() {
+        let g = B409::GENERATOR;
+        assert!(g.is_on_curve(), "Test for B409 is_on_curve failed!");
+        assert_eq!(B409::A6_SQRT.squaring(), B409::A6);
+    }
+
+    #[test]
+    fn test_generator_order() {
+        // [n - 1]G = -G, i.e. G generates the subgroup of prime order n
+        let g = B409::GENERATOR;
+        assert_eq!(
+            g * -FnB409::one(),
+            -g,
+            "Test for B409 generator order failed!"
+        );
+        assert_eq!(g * FnB409::zero(), B409::IDENTITY);
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let g = B409::GENERATOR;
+        let k = FnB409::from_hex_string(
+            "0xa640544506f38b71aaeea938c1b7e036a32a5d1c98def66efe9b6400284cfa54267ace9b954ae4cca380aa61027d4d04b3acba",
+        );
+        let expected = B409Point::from_affine(
+            Fq409::from_hex_string(
+                "0x001468cf77f110d815c72b4cfeb43de2304ccf6434af238c320c946a7848dcd11b852f8df964f996b43ee4131f495b63e415091d",
+            ),
+            Fq409::from_hex_string(
+                "0x00511c70d05c6e9ca0eb77714998f87b596d076e04e8bae607a445f9a29eeb3c469d244bc1eb5e037e952d3c0af3e7d9969ed454",
+            ),
+        );
+        assert_eq!(
+            g * k,
+            expected,
+            "Test for B409 scalar multiplication failed!"
+        );
+        assert_eq!(
+            B409::montgomery_scalar_mul(&g, k),
+            expected,
+            "Test for B409 montgomery scalar multiplication failed!"
+        );
+    }
+
+    #[test]
+    fn test_addition() {
+        let (p, q, p_plus_q, p_double) = (
+            B409Point::from_affine(
+                Fq409::from_hex_string(
+                    "0x001468cf77f110d815c72b4cfeb43de2304ccf6434af238c320c946a7848dcd11b852f8df964f996b43ee4131f495b63e415091d",
+                ),
+                Fq409::from_hex_string(
+                    "0x00511c70d05c6e9ca0eb77714998f87b596d076e04e8bae607a445f9a29eeb3c469d244bc1eb5e037e952d3c0af3e7d9969ed454",
+                ),
+            ),
+            B409Point::from_affine(
+                Fq409::from_hex_string(
+                    "0x00d3cb5fec0150e0e4919ee04432569b02a639aee36f112d78a5e2aeb46275a9ff466939e5dfa129f3cbf3d5045d3c892d5852bb",
+                ),
+                Fq409::from_hex_string(
+                    "0x01c4c1683df090b1c22b81f998e63762a0de63ebec668e15fc80f543f6657781d41ed21ac8a908948e79c8c25e4d5f9b962a041b",
+                ),
+            ),
+            B409Point::from_affine(
+                Fq409::from_hex_string(
+                    "0x00e36503b64e7c00b372bbabab55040c176162298f5f79b7ece5c185558c0fb3ccbfee58d05d6ebbcd471b28f9d0e9139bf9662b",
+                ),
+                Fq409::from_hex_string(
+                    "0x004b1453df29a2b88d80e4f8bd4350683aaf062e68bf29b31427569d9742c1eb340b547e8f492ed171ae10f4a5898b734f416f1d",
+                ),
+            ),
+            B409Point::from_affine(
+                Fq409::from_hex_string(
+                    "0x0157b20f159d72fa34cfb61467b913dc996c67d85557be5a372374bfd5681d6a45a36182244269c32bfe68b4f988d9bb86e18309",
+                ),
+                Fq409::from_hex_string(
+                    "0x019d296289f94ff5a6e98ab9117f156f8f97862a24469e3d777b05dcbd973d32af0f4a5af3369de3ba460b46e175439930421fdf",
+                ),
+            ),
+        );
+        // mixed addition and doubling
+        assert_eq!(p + q, p_plus_q, "Test for B409 mixed addition failed!");
+        assert_eq!(p + p, p_double, "Test for B409 doubling failed!");
+        // general addition with Z != 1 on both sides
+        let z = B409::GENERATOR.x;
+        let q_projective = B409Point::new(q.x * z, q.y * z.squaring(), z);
+        assert!(q_projective.is_on_curve() && !q_projective.is_affine());
+        assert_eq!(
+            B409::add(&(p + p), &q_projective),
+            p_double + q,
+            "Test for B409 projective addition failed!"
+        );
+        assert_eq!(p + q_projective + (-q), p);
+    }
+}
@@ -0,0 +1,125 @@
+/// Instantiation of Binary Curve with B-571, E(\bar{K}): y^2 + xy = x^3 + x^2 + b, where \bar{K} = GF(2)[X] / X^571 + X^10 + X^5 + X^2 + 1
+/// b = 0x02f40e7e2221f295de297117b7f3d62f5c6a97ffcb8ceff1cd6ba8ce4a9a18ad84ffabbd8efa59332be7ad6756a66e294afd185a78ff12aa520e4de739baca0c7ffeff7f2955727a
+use super::curve::ProjectivePoint;
+use crate::binary_field::fq571::{self, Fq571, N};
+use crate::define_binary_curve;
+use crate::scalar_field::fnb571::FnB571;
+
+// base point of sect571r1 which generates the subgroup of prime order n
+define_binary_curve!(
+    B571, fq571::Fq571, FnB571,
+    name: "sect571r1",
+    oid: "1.3.132.0.39",
+    a2: "0x1",
+    a6: "0x02f40e7e2221f295de297117b7f3d62f5c6a97ffcb8ceff1cd6ba8ce4a9a18ad84ffabbd8efa59332be7ad6756a66e294afd185a78ff12aa520e4de739baca0c7ffeff7f2955727a",
+    generator_x: "0x0303001d34b856296c16c0d40d3cd7750a93d1d2955fa80aa5f40fc8db7b2abdbde53950f4c0d293cdd711a35b67fb1499ae60038614f1394abfa3b4c850d927e1e7769c8eec2d19",
+    generator_y: "0x037bf27342da639b6dccfffeb73d69d78c6c27a6009cbbca1980f8533921e8a684423e43bab08a576291af8f461bb2a8b3531d2f0485c19b16e2f1516e23dd3c1a4827af1b8ac15b",
+    order: "0x3ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe661ce18ff55987308059b186823851ec7dd9ca1161de93d5174d66e8382e9bb2fe84e47",
+    cofactor: 2,
+);
+
+pub type B571Point = ProjectivePoint<N, Fq571, B571>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary_curve::curve::BinaryCurve;
+    use crate::binary_field::BinaryField;
+    use crate::scalar_field::ScalarField;
+
+    #[test]
+    fn test_is_on_curve() {
+        let g = B571::GENERATOR;
+        assert!(g.is_on_curve(), "Test for B571 is_on_curve failed!");
+        assert_eq!(B571::A6_SQRT.squaring(), B571::A6);
+    }
+
+    #[test]
+    fn test_generator_order() {
+        // [n - 1]G = -G, i.e. G generates the subgroup of prime order n
+        let g = B571::GENERATOR;
+        assert_eq!(
+            g * -FnB571::one(),
+            -g,
+            "Test for B571 generator order failed!"
+        );
+        assert_eq!(g * FnB571::zero(), B571::IDENTITY);
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let g = B571::GENERATOR;
+        let k = FnB571::from_hex_string(
+            "0x78c2e7b22c47ff4bc7556d8e53609fbf5c166f162aa5e7f636aa932326111a471918ad8fdac9077b1d59222401474348f670b05ac28cfacc76b117f7682c1e03c988cd9c8e744c",
+        );
+        let expected = B571Point::from_affine(
+            Fq571::from_hex_string(
+                "0x00cdf5ad40d50b1be71c02ac3e495ca661f9c6c8880de28bcede2d29d8654d6e47bdef64ef10e266e9f61ea7e53b08740b55dd0b4108195f5365b036b5d167354def02756e35ba59",
+            ),
+            Fq571::from_hex_string(
+                "0x07185526a88f7feddb292f3646d01cb4672dd29c4a336890579dff2da89c0ed525b6c04cde94ab5f4d7f7a8721448fdd352e4bc021d02169e0cd52cdbf17a3b961aa61b08bee9d4e",
+            ),
+        );
+        assert_eq!(
+            g * k,
+            expected,
+            "Test for B571 scalar multiplication failed!"
+        );
+        assert_eq!(
+            B571::montgomery_scalar_mul(&g, k),
+            expected,
+            "Test for B571 montgomery scalar multiplication failed!"
+        );
+    }
+
+    #[test]
+    fn test_addition() {
+        let (p, q, p_plus_q, p_double) = (
+            B571Point::from_affine(
+                Fq571::from_hex_string(
+                    "0x00cdf5ad40d50b1be71c02ac3e495ca661f9c6c8880de28bcede2d29d8654d6e47bdef64ef10e266e9f61ea7e53b08740b55dd0b4108195f5365b036b5d167354def02756e35ba59",
+                ),
+                Fq571::from_hex_string(
+                    "0x07185526a88f7feddb292f3646d01cb4672dd29c4a336890579dff2da89c0ed525b6c04cde94ab5f4d7f7a8721448fdd352e4bc021d02169e0cd52cdbf17a3b961aa61b08bee9d4e",
+                ),
+            ),
+            B571Point::from_affine(
+                Fq571::from_hex_string(
+                    "0x018596c8247c365b3b3777535e2904eac5baf8b273aec7967d55f6ba0fa1020a571330e2efed23208b56a629db3767893dcc7c9331aefb936f713e74992ff09481af32fb50911cbd",
+                ),
+                Fq571::from_hex_string(
+                    "0x012dff517bdd1f8040f9d38fc75021fd45bce81d564fcd4086c9fa9718d5da1ebe5e7fa71ac318b1ce452a9f92bde7fc6772a249810f7ab3f7db8dcca6a7081cb223f21283fb855c",
+                ),
+            ),
+            B571Point::from_affine(
+                Fq571::from_hex_string(
+                    "0x037e8750ee1db3d20657bfdc228a06c41a7580a5ba87800568fddfd4e4289e14de31bf786e4d9a567d6d859894d55a5f94eb4c4c30d363e2ecf163e6ba0d1aa2ec93e2bbf127a829",
+                ),
+                Fq571::from_hex_string(
+                    "0x04a17dd278716bac0fe65f9608d2c4fdb4dbc5355665deadb2ffd3363d4b04b1d07f293055e684f1d35f43710692029a8da91e09d05eac7a80071eb0452e9c05302d6ed746f51a5e",
+                ),
+            ),
+            B571Point::from_affine(
+                Fq571::from_hex_string(
+                    "0x02dd05e089cddd8417f23f5ac81ed05310ae5adc87d3a107f1b2c8ea2f8d7547e1ddbd7dda08e31c448a3cb74840683b151daf85da40c596acb9c4f0cec3670644665b07ebbf89d6",
+                ),
+                Fq571::from_hex_string(
+                    "0x0706281bc0f2feb4fb85153e43dcc03af4f849236376c3ea048adc5a8b169544fb6e608d96d1955b15b3bf8a7ad21a621a9dd21c86e944474b2db4222d04cf4494e1fc89884cfeb3",
+                ),
+            ),
+        );
+        // mixed addition and doubling
+        assert_eq!(p + q, p_plus_q, "Test for B571 mixed addition failed!");
+        assert_eq!(p + p, p_double, "Test for B571 doubling failed!");
+        // general addition with Z != 1 on both sides
+        let z = B571::GENERATOR.x;
+        let q_projective = B571Point::new(q.x * z, q.y * z.squaring(), z);
+        assert!(q_projective.is_on_curve() && !q_projective.is_affine());
+        assert_eq!(
+            B571::add(&(p + p), &q_projective),
+            p_double + q,
+            "Test for B571 projective addition failed!"
+        );
+        assert_eq!(p + q_projective + (-q), p);
+    }
+}
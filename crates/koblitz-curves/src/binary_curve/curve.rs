@@ -1,5 +1,8 @@
 /// Elliptic curve group based on binary field, say E(\bar{K}): x^2 + xy = x^3 + a_2 * x^2 + a_6, where \bar{K} is the field on which EC group elements is defined
+use super::koblitz_tau::{self, Koblitz, ZTau, ZTauExpansion};
 use crate::binary_field::BinaryField;
+use crate::binary_field::unreduced::Unreduced;
+use crate::scalar_field::ScalarField;
 use core::ops::{Add, Mul, Neg, Sub};
 use std::fmt::Debug;
 use std::marker::PhantomData;
@@ -28,14 +31,61 @@ impl<const N: usize, Field: BinaryField<N>, Curve: BinaryCurve<N, Field>> Partia
         if self.is_identity() || other.is_identity() {
             return false;
         }
-        ((self.x / self.z) == (other.x / other.z))
-            && ((self.y / self.z.squaring()) == (other.y / other.z.squaring()))
+        // X1 / Z1 = X2 / Z2 and Y1 / Z1^2 = Y2 / Z2^2, cross multiplied to avoid inversions
+        ((self.x * other.z) == (other.x * self.z))
+            && ((self.y * other.z.squaring()) == (other.y * self.z.squaring()))
     }
 }
 
 impl<const N: usize, Field: BinaryField<N>, Curve: BinaryCurve<N, Field>>
     ProjectivePoint<N, Field, Curve>
 {
+    // point with Lopez-Dahab coordinates (X, Y, Z), i.e. affine point (X / Z, Y / Z^2)
+    pub fn new(x: Field, y: Field, z: Field) -> Self {
+        Self {
+            x,
+            y,
+            z,
+            marker: PhantomData,
+        }
+    }
+
+    // affine point (x, y), i.e. Z = 1
+    pub fn from_affine(x: Field, y: Field) -> Self {
+        Self::new(x, y, Field::one())
+    }
+
+    pub fn identity() -> Self {
+        Curve::IDENTITY
+    }
+
+    pub fn generator() -> Self {
+        Curve::GENERATOR
+    }
+
+    // affine coordinates (X / Z, Y / Z^2), the identity has no affine representation
+    pub fn to_affine(&self) -> Option<(Field, Field)> {
+        if self.is_identity() {
+            return None;
+        }
+        if self.is_affine() {
+            return Some((self.x, self.y));
+        }
+        Some((self.x / self.z, self.y / self.z.squaring()))
+    }
+
+    // normalize many points to Z = 1 with a single inversion, the identity is left as it is
+    pub fn batch_normalize(points: &mut [Self]) {
+        let mut z_inv = points.iter().map(|p| p.z).collect::<Vec<_>>();
+        Field::batch_invert(&mut z_inv);
+        for (p, z_inv) in points.iter_mut().zip(z_inv) {
+            if p.is_identity() || p.is_affine() {
+                continue;
+            }
+            *p = Self::from_affine(p.x * z_inv, p.y * z_inv.squaring());
+        }
+    }
+
     pub fn is_affine(&self) -> bool {
         self.z.is_one()
     }
@@ -49,12 +99,75 @@ impl<const N: usize, Field: BinaryField<N>, Curve: BinaryCurve<N, Field>>
     }
 }
 
-impl<const N: usize, Field: BinaryField<N>, Curve: BinaryCurve<N, Field>> Mul<Field>
-    for ProjectivePoint<N, Field, Curve>
+impl<const N: usize, Field, Curve> ProjectivePoint<N, Field, Curve>
+where
+    Field: BinaryField<N>,
+    Curve: BinaryCurve<N, Field> + Koblitz,
+{
+    // Frobenius endomorphism \tau^k(X, Y, Z) = (X^{2^k}, Y^{2^k}, Z^{2^k}),
+    // which keeps points on curve since a_2 and a_6 are in GF(2) on Koblitz curves
+    pub fn frobenius(&self, k: usize) -> Self {
+        let mut result = *self;
+        for _ in 0..k {
+            result = Self::new(
+                result.x.squaring(),
+                result.y.squaring(),
+                result.z.squaring(),
+            );
+        }
+        result
+    }
+}
+
+// evaluate a \tau-adic expansion \sum_i d_i * \tau^i on a point with Frobenius-and-add (Horner's rule),
+// digits d_i are expected to be 0, 1 or -1
+impl<const N: usize, Field, Curve> Mul<&ZTauExpansion> for ProjectivePoint<N, Field, Curve>
+where
+    Field: BinaryField<N>,
+    Curve: BinaryCurve<N, Field> + Koblitz,
+{
+    type Output = Self;
+
+    fn mul(self, expansion: &ZTauExpansion) -> Self {
+        let (one, minus_one) = (koblitz_tau::Z::from(1), koblitz_tau::Z::from(-1));
+        let neg_self = -self;
+        let mut result = Curve::IDENTITY;
+        for d in expansion.digits().iter().rev() {
+            result = result.frobenius(1);
+            if *d == one {
+                result = result + self;
+            } else if *d == minus_one {
+                result = result + neg_self;
+            } else {
+                assert!(d.is_zero(), "Invalid digit of tauNAF expansion!");
+            }
+        }
+        result
+    }
+}
+
+// evaluate an element of Z[\tau] on a point through its tauNAF expansion
+impl<const N: usize, Field, Curve> Mul<ZTau<Curve>> for ProjectivePoint<N, Field, Curve>
+where
+    Field: BinaryField<N>,
+    Curve: BinaryCurve<N, Field> + Koblitz,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: ZTau<Curve>) -> Self {
+        self * &rhs.tauNAF()
+    }
+}
+
+impl<const N: usize, Field, Curve, Scalar> Mul<Scalar> for ProjectivePoint<N, Field, Curve>
+where
+    Field: BinaryField<N>,
+    Curve: BinaryCurve<N, Field, Scalar = Scalar>,
+    Scalar: ScalarField,
 {
     type Output = Self;
 
-    fn mul(self, scalar: Field) -> Self {
+    fn mul(self, scalar: Scalar) -> Self {
         // Curve::montgomery_scalar_mul(&self, scalar)
         Curve::fast_montgomery_scalar_mul(&self, scalar)
     }
@@ -98,6 +211,10 @@ impl<const N: usize, Field: BinaryField<N>, Curve: BinaryCurve<N, Field>> Neg
 pub trait BinaryCurve<const N: usize, Field: BinaryField<N>>:
     Debug + Clone + Copy + Sized + Eq + PartialEq
 {
+    // scalar field of the prime subgroup, i.e. Z / nZ where n is the order of GENERATOR
+    type Scalar: ScalarField;
+    // #E(GF(2^m)) = COFACTOR * n
+    const COFACTOR: u64;
     const A2: Field;
     const A6: Field;
     const IDENTITY: ProjectivePoint<N, Field, Self>;
@@ -106,8 +223,15 @@ pub trait BinaryCurve<const N: usize, Field: BinaryField<N>>:
 
     fn is_on_curve(p: &ProjectivePoint<N, Field, Self>) -> bool {
         let (X, Y, Z) = (p.x, p.y, p.z);
-        let (X2, Y2, Z2, XZ) = (X * X, Y * Y, Z * Z, X * Z);
-        Y2 + Y * XZ == X2 * XZ + Self::A2 * X2 * Z2 + Self::A6 * (Z2 * Z2)
+        let (X2, Z2, XZ) = (X.squaring(), Z.squaring(), X * Z);
+        // Y^2 + Y * XZ + X^2 * XZ + a_2 * X^2 * Z^2 + a_6 * Z^4 = 0 with a single reduction of the sum
+        Unreduced::square(&Y)
+            .mul_add(&Y, &XZ)
+            .mul_add(&X2, &XZ)
+            .mul_add(&(Self::A2 * X2), &Z2)
+            .mul_add(&Self::A6, &Z2.squaring())
+            .reduce()
+            .is_zero()
     }
 
     // Lopez-Dahab Coordinates based point addition
@@ -128,12 +252,18 @@ pub trait BinaryCurve<const N: usize, Field: BinaryField<N>>:
         let ((X1, Y1, Z1), (X2, Y2, Z2)) = ((lft.x, lft.y, lft.z), (rhs.x, rhs.y, rhs.z));
         // mixed Coordinates
         if rhs.is_affine() {
-            let (A, B) = (Y1 + Y2 * (Z1 * Z1), X1 + X2 * Z1);
+            let (A, B) = (Y1 + Y2 * Z1.squaring(), X1 + X2 * Z1);
             let C = B * Z1;
-            let Z3 = C * C;
+            let Z3 = C.squaring();
             let D = X2 * Z3;
-            let X3 = A * A + C * (A + B * B + Self::A2 * C);
-            let Y3 = (D + X3) * (A * C + Z3) + (Y2 + X2) * (Z3 * Z3);
+            // sums of products are reduced once
+            let X3 = Unreduced::square(&A)
+                .mul_add(&C, &(A + B.squaring() + Self::A2 * C))
+                .reduce();
+            let AC_Z3 = (Unreduced::product(&A, &C) + Z3).reduce();
+            let Y3 = Unreduced::product(&(D + X3), &AC_Z3)
+                .mul_add(&(Y2 + X2), &Z3.squaring())
+                .reduce();
             ProjectivePoint {
                 x: X3,
                 y: Y3,
@@ -142,14 +272,20 @@ pub trait BinaryCurve<const N: usize, Field: BinaryField<N>>:
             }
         } else {
             let (A, B) = (X1 * Z2, X2 * Z1);
-            let (C, D, E) = (A * A, B * B, A + B);
+            let (C, D, E) = (A.squaring(), B.squaring(), A + B);
             let F = C + D;
-            let (G, H) = (Y1 * (Z2 * Z2), Y2 * (Z1 * Z1));
+            let (G, H) = (Y1 * Z2.squaring(), Y2 * Z1.squaring());
             let I = G + H;
             let J = I * E;
             let Z3 = F * Z1 * Z2;
-            let X3 = A * (H + D) + B * (C + G);
-            let Y3 = (A * J + F * G) * F + (J + Z3) * X3;
+            // sums of products are reduced once
+            let X3 = Unreduced::product(&A, &(H + D))
+                .mul_add(&B, &(C + G))
+                .reduce();
+            let AJ_FG = Unreduced::product(&A, &J).mul_add(&F, &G).reduce();
+            let Y3 = Unreduced::product(&AJ_FG, &F)
+                .mul_add(&(J + Z3), &X3)
+                .reduce();
             ProjectivePoint {
                 x: X3,
                 y: Y3,
@@ -165,11 +301,15 @@ pub trait BinaryCurve<const N: usize, Field: BinaryField<N>>:
             return Self::IDENTITY;
         }
         let (X1, Y1, Z1) = (lft.x, lft.y, lft.z);
-        let A = Z1 * Z1;
-        let (B, C) = (Self::A6 * (A * A), X1 * X1);
+        let A = Z1.squaring();
+        let (B, C) = (Self::A6 * A.squaring(), X1.squaring());
         let Z3 = A * C;
-        let X3 = C * C + B;
-        let Y3 = (Y1 * Y1 + Self::A2 * Z3 + B) * X3 + Z3 * B;
+        let X3 = C.squaring() + B;
+        // sums of products are reduced once
+        let Y1_sq_A2Z3_B = (Unreduced::square(&Y1).mul_add(&Self::A2, &Z3) + B).reduce();
+        let Y3 = Unreduced::product(&Y1_sq_A2Z3_B, &X3)
+            .mul_add(&Z3, &B)
+            .reduce();
         ProjectivePoint {
             x: X3,
             y: Y3,
@@ -194,7 +334,7 @@ pub trait BinaryCurve<const N: usize, Field: BinaryField<N>>:
     // General Montgomery scalar multiplication
     fn montgomery_scalar_mul(
         p: &ProjectivePoint<N, Field, Self>,
-        scalar: Field,
+        scalar: Self::Scalar,
     ) -> ProjectivePoint<N, Field, Self> {
         // trivial checks at the very first
         if scalar.is_zero() {
@@ -209,7 +349,7 @@ pub trait BinaryCurve<const N: usize, Field: BinaryField<N>>:
         // binary representation of scalar field
         let n = scalar.bits(true);
         let l = n.len();
-        let (mut P1, mut P2) = (p.clone(), *p + *p);
+        let (mut P1, mut P2) = (*p, *p + *p);
         // using montgomery ladder
         for i in (0..l - 1).rev() {
             if n[i] == 0u8 {
@@ -237,13 +377,121 @@ pub trait BinaryCurve<const N: usize, Field: BinaryField<N>>:
         let ((Xn, Zn), (Xm, Zm)) = ((pn.0, pn.1), (pm.0, pm.1));
         let (XmZn, XnZm) = (Xm * Zn, Xn * Zm);
         let Z_m_plus_n = (XmZn + XnZm).squaring();
-        (Z_m_plus_n * X_m_minus_n + XmZn * XnZm, Z_m_plus_n)
+        (
+            Unreduced::product(&Z_m_plus_n, &X_m_minus_n)
+                .mul_add(&XmZn, &XnZm)
+                .reduce(),
+            Z_m_plus_n,
+        )
+    }
+
+    // mont_double over the branch-free field operations
+    fn ct_mont_double(pn: &(Field, Field)) -> (Field, Field) {
+        let (Xn, Zn) = (pn.0, pn.1);
+        let (Xn_sq, Zn_sq) = (Xn.ct_squaring(), Zn.ct_squaring());
+        (
+            Xn_sq.ct_add(&Self::A6_SQRT.ct_mul(&Zn_sq)).ct_squaring(),
+            Xn_sq.ct_mul(&Zn_sq),
+        )
+    }
+
+    // mont_add over the branch-free field operations, reducing the sum of products once
+    fn ct_mont_add(pn: &(Field, Field), pm: &(Field, Field), X_m_minus_n: Field) -> (Field, Field) {
+        let ((Xn, Zn), (Xm, Zm)) = ((pn.0, pn.1), (pm.0, pm.1));
+        let (XmZn, XnZm) = (Xm.ct_mul(&Zn), Xn.ct_mul(&Zm));
+        let Z_m_plus_n = XmZn.ct_add(&XnZm).ct_squaring();
+        (
+            Field::reduce_unchecked(
+                Z_m_plus_n.polynomial().ct_mul(&X_m_minus_n.polynomial())
+                    + XmZn.polynomial().ct_mul(&XnZm.polynomial()),
+            ),
+            Z_m_plus_n,
+        )
+    }
+
+    // Montgomery ladder over all BITS bits of k from (O, P), with conditional swaps instead of branches on the bits
+    // and the branch-free field operations whatever the build is, so that it is safe for secret scalars,
+    // returns ([k]P, [k + 1]P) in x-only projective coordinates where x is the affine x-coordinate of P
+    fn mont_ladder(k: &Self::Scalar, x: Field) -> ((Field, Field), (Field, Field)) {
+        let ((mut Xn, mut Zn), (mut Xm, mut Zm)) =
+            ((Field::one(), Field::zero()), (x, Field::one()));
+        let mut swap = 0u8;
+        for i in (0..Self::Scalar::BITS).rev() {
+            // keep P1 = [l]P and P2 = [l + 1]P with the invariant P2 - P1 = P
+            let bit = k.bit(i);
+            swap ^= bit;
+            Field::conditional_swap(&mut Xn, &mut Xm, swap);
+            Field::conditional_swap(&mut Zn, &mut Zm, swap);
+            swap = bit;
+            ((Xn, Zn), (Xm, Zm)) = (
+                Self::ct_mont_double(&(Xn, Zn)),
+                Self::ct_mont_add(&(Xn, Zn), &(Xm, Zm), x),
+            );
+        }
+        Field::conditional_swap(&mut Xn, &mut Xm, swap);
+        Field::conditional_swap(&mut Zn, &mut Zm, swap);
+        ((Xn, Zn), (Xm, Zm))
+    }
+
+    // [k]P with the fixed-length ladder and branch-free field operations whatever the build is, for secret
+    // scalars such as nonces and keys, [k]P = O and [k]P = -P are chosen with conditional selects instead of
+    // early returns, only the affine coordinates of the public point P are computed in variable time
+    fn ladder_scalar_mul(
+        p: &ProjectivePoint<N, Field, Self>,
+        scalar: Self::Scalar,
+    ) -> ProjectivePoint<N, Field, Self> {
+        let Some((x, y)) = p.to_affine() else {
+            return Self::IDENTITY;
+        };
+        // the ladder divides by x(P), so the point (0, sqrt(b)) of order 2 goes through the generic ladder
+        if x.is_zero() {
+            return Self::montgomery_scalar_mul(p, scalar);
+        }
+        let ((Xn, Zn), (Xm, Zm)) = Self::mont_ladder(&scalar, x);
+        // [k]P = O if Zn = 0, and [k + 1]P = O if Zm = 0 which means [k]P = -P, divide by one instead of zero
+        let (n_is_identity, m_is_identity) = (Zn.ct_is_zero(), Zm.ct_is_zero());
+        let one = Field::one();
+        let Zn = Field::conditional_select(&Zn, &one, n_is_identity);
+        let Zm = Field::conditional_select(&Zm, &one, m_is_identity);
+        // a single inversion of Zn * Zm * x gives the inverses of all three
+        let ZnZm = Zn.ct_mul(&Zm);
+        let inv = ZnZm.ct_mul(&x).ct_inv();
+        let Xn = Xn.ct_mul(&inv.ct_mul(&Zm.ct_mul(&x)));
+        let Xm = Xm.ct_mul(&inv.ct_mul(&Zn.ct_mul(&x)));
+        let x_inv = inv.ct_mul(&ZnZm);
+        // Y_n = (Xn + x) * ((Xn + x) * (Xm + x) + x^2 + y) / x + y
+        let t = Xn.ct_add(&x);
+        let Yn = t
+            .ct_mul(&t.ct_mul(&Xm.ct_add(&x)).ct_add(&x.ct_squaring()).ct_add(&y))
+            .ct_mul(&x_inv)
+            .ct_add(&y);
+        // -P = (x, x + y)
+        let (Xn, Yn) = (
+            Field::conditional_select(&Xn, &x, m_is_identity),
+            Field::conditional_select(&Yn, &x.ct_add(&y), m_is_identity),
+        );
+        let identity = Self::IDENTITY;
+        ProjectivePoint::new(
+            Field::conditional_select(&Xn, &identity.x, n_is_identity),
+            Field::conditional_select(&Yn, &identity.y, n_is_identity),
+            Field::conditional_select(&one, &identity.z, n_is_identity),
+        )
+    }
+
+    // the constant-time backend takes the fixed-length ladder, which never branches on the scalar
+    #[cfg(feature = "constant-time")]
+    fn fast_montgomery_scalar_mul(
+        p: &ProjectivePoint<N, Field, Self>,
+        scalar: Self::Scalar,
+    ) -> ProjectivePoint<N, Field, Self> {
+        Self::ladder_scalar_mul(p, scalar)
     }
 
     // Fast Montgomery scalar multiplication specially for binary curve
+    #[cfg(not(feature = "constant-time"))]
     fn fast_montgomery_scalar_mul(
         p: &ProjectivePoint<N, Field, Self>,
-        scalar: Field,
+        scalar: Self::Scalar,
     ) -> ProjectivePoint<N, Field, Self> {
         // trivial checks at the very first
         if scalar.is_zero() {
@@ -252,34 +500,50 @@ pub trait BinaryCurve<const N: usize, Field: BinaryField<N>>:
         if scalar.is_one() {
             return *p;
         }
-        if p.is_identity() {
+        // the ladder works on the affine coordinates of P
+        let Some((x, y)) = p.to_affine() else {
             return Self::IDENTITY;
+        };
+        // the ladder divides by x(P), so the point (0, sqrt(b)) of order 2 goes through the generic ladder
+        if x.is_zero() {
+            return Self::montgomery_scalar_mul(p, scalar);
         }
-        // binary representation of scalar field
-        let n = scalar.bits(true);
-        let l = n.len();
-        // P1, P2
-        let ((mut Xn, mut Zn), (mut Xm, mut Zm)) = ((p.x, p.z), Self::mont_double(&(p.x, p.z)));
         // P = P2 - P1
-        let (Xm_minus_n, Ym_minus_n) = (p.x, p.y);
-        // using montgomery ladder
-        for i in (0..l - 1).rev() {
-            if n[i] == 0u8 {
-                // P1 = P1 + P1, P2 = P1 + P2
-                ((Xn, Zn), (Xm, Zm)) = (
-                    Self::mont_double(&(Xn, Zn)),
-                    Self::mont_add(&(Xn, Zn), &(Xm, Zm), Xm_minus_n),
-                );
-            } else {
-                // P1 = P1 + P2, P2 = P2 + P2
-                ((Xn, Zn), (Xm, Zm)) = (
-                    Self::mont_add(&(Xn, Zn), &(Xm, Zm), Xm_minus_n),
-                    Self::mont_double(&(Xm, Zm)),
-                );
+        let (Xm_minus_n, Ym_minus_n) = (x, y);
+        let ((Xn, Zn), (Xm, Zm)) = {
+            // binary representation of scalar field
+            let n = scalar.bits(true);
+            let l = n.len();
+            // P1, P2
+            let ((mut Xn, mut Zn), (mut Xm, mut Zm)) =
+                ((x, Field::one()), Self::mont_double(&(x, Field::one())));
+            // using montgomery ladder
+            for i in (0..l - 1).rev() {
+                if n[i] == 0u8 {
+                    // P1 = P1 + P1, P2 = P1 + P2
+                    ((Xn, Zn), (Xm, Zm)) = (
+                        Self::mont_double(&(Xn, Zn)),
+                        Self::mont_add(&(Xn, Zn), &(Xm, Zm), Xm_minus_n),
+                    );
+                } else {
+                    // P1 = P1 + P2, P2 = P2 + P2
+                    ((Xn, Zn), (Xm, Zm)) = (
+                        Self::mont_add(&(Xn, Zn), &(Xm, Zm), Xm_minus_n),
+                        Self::mont_double(&(Xm, Zm)),
+                    );
+                }
             }
+            ((Xn, Zn), (Xm, Zm))
+        };
+        // [n]P = O, or [n + 1]P = O which means [n]P = -P
+        if Zn.is_zero() {
+            return Self::IDENTITY;
+        }
+        if Zm.is_zero() {
+            return Self::neg(p);
         }
         // convert to affine coordinates
-        ((Xn, Zn), (Xm, _)) = ((Xn / Zn, Field::one()), (Xm / Zm, Field::one()));
+        let ((Xn, Zn), Xm) = ((Xn / Zn, Field::one()), Xm / Zm);
         // restore Yn with restored affine coordinates of [n]P and [m]P
         // Y_n = (Xn + Xm_minus_n) * ((Xn + Xm_minus_n) * (Xm + Xm_minus_n) + Xm_minus_n^2 + Ym_minus_n) / Xm_minus_n + Ym_minus_n
         let Xn_Plus_Xm_minus_n = Xn + Xm_minus_n;
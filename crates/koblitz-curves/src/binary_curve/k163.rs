@@ -0,0 +1,98 @@
+/// Instantiation of Binary Curve with K-163, E(\bar{K}): y^2 + xy = x^3 + x^2 + 1, where \bar{K} = GF(2)[X] / X^163 + X^7 + X^6 + X^3 + 1
+use super::curve::ProjectivePoint;
+use super::koblitz::KoblitzCurve;
+use super::koblitz_tau::Koblitz;
+use crate::binary_field::fq163::{self, Fq163, N};
+use crate::define_binary_curve;
+use crate::scalar_field::fn163::Fn163;
+
+// base point of sect163k1 which generates the subgroup of prime order n
+define_binary_curve!(
+    K163, fq163::Fq163, Fn163,
+    name: "sect163k1",
+    oid: "1.3.132.0.1",
+    a2: "0x1",
+    a6: "0x1",
+    generator_x: "0x02fe13c0537bbc11acaa07d793de4e6d5e5c94eee8",
+    generator_y: "0x0289070fb05d38ff58321f2e800536d538ccdaa3d9",
+    order: "0x4000000000000000000020108a2e0cc0d99f8a5ef",
+    cofactor: 2,
+);
+
+pub type K163Point = ProjectivePoint<N, Fq163, K163>;
+
+// a_2 = 1, so that \mu = 1
+impl Koblitz for K163 {
+    const MU: i64 = 1;
+}
+
+impl KoblitzCurve<N, Fq163> for K163 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary_curve::curve::BinaryCurve;
+    use crate::binary_curve::koblitz::TauNafwTable;
+    use crate::binary_field::BinaryField;
+    use crate::scalar_field::ScalarField;
+
+    #[test]
+    fn test_is_on_curve() {
+        let g = K163::GENERATOR;
+        assert!(g.is_on_curve(), "Test for K163 is_on_curve failed!");
+    }
+
+    #[test]
+    fn test_generator_order() {
+        // [n - 1]G = -G, i.e. G generates the subgroup of prime order n
+        let g = K163::GENERATOR;
+        assert_eq!(
+            g * -Fn163::one(),
+            -g,
+            "Test for K163 generator order failed!"
+        );
+        assert_eq!(g * Fn163::zero(), K163::IDENTITY);
+    }
+
+    #[test]
+    fn test_frobenius() {
+        // \tau(P) = [\lambda]P, where \lambda is a root of \lambda^2 - \lambda + 2 = 0 (mod n), \mu = 1
+        let lambda = Fn163::from_hex_string("0x381afd9e3493dccbfc2faf1d284e6d34ebd67a6da");
+        assert_eq!(lambda * lambda - lambda + Fn163::from(2u64), Fn163::zero());
+        assert!(K163::A2.is_one() && K163::MU == 1);
+        let g = K163::GENERATOR;
+        assert_eq!(
+            g.frobenius(1),
+            g * lambda,
+            "Test for Frobenius eigenvalue failed!"
+        );
+        // \tau^m is the identity map
+        assert_eq!(g.frobenius(163), g);
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let g = K163::GENERATOR;
+        let k = Fn163::from_hex_string("0x67164890d49d0ac1e5b8063831360a4092b850ad");
+        let expected = K163Point::from_affine(
+            Fq163::from_hex_string("0x022c743dfa988c74c3d6edd653df8abcaf5bb31ac6"),
+            Fq163::from_hex_string("0x049c2cf7d28c3402101bf5c2fb0f48acdacbdb3f36"),
+        );
+        assert_eq!(
+            g * k,
+            expected,
+            "Test for K163 scalar multiplication failed!"
+        );
+        assert_eq!(
+            K163::montgomery_scalar_mul(&g, k),
+            expected,
+            "Test for K163 montgomery scalar multiplication failed!"
+        );
+        let table = TauNafwTable::new(&g, 5);
+        assert_eq!(
+            table.scalar_mul(k),
+            expected,
+            "Test for K163 tauNAF_w scalar multiplication failed!"
+        );
+    }
+}
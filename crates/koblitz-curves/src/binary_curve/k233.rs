@@ -1,39 +1,48 @@
 /// Instantiation of Binary Curve with K-233, E(\bar{K}): x^2 + xy = x^3 + 1, where \bar{K} = GF(2)[X] / X^233 + X^74 + 1
-use super::curve::{BinaryCurve, ProjectivePoint};
-use crate::binary_field::{N, fq233::Fq233, polynomial::BinaryPolynomial};
-use std::marker::PhantomData;
+use super::curve::ProjectivePoint;
+use super::koblitz::KoblitzCurve;
+use super::koblitz_tau::Koblitz;
+use crate::binary_field::fq233::{self, Fq233, N};
+use crate::define_binary_curve;
+use crate::ecdsa::{SigningKey, VerifyingKey};
+use crate::scalar_field::fn233::Fn233;
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub struct K233;
+// sect233k1 in SEC 2 v2, section 3.3.1, whose base point generates the subgroup of prime order n
+define_binary_curve!(
+    K233, fq233::Fq233, Fn233,
+    name: "sect233k1",
+    oid: "1.3.132.0.26",
+    a2: "0x0",
+    a6: "0x1",
+    generator_x: "0x017232ba853a7e731af129f22ff4149563a419c26bf50a4c9d6eefad6126",
+    generator_y: "0x01db537dece819b7f70f555a67c427a8cd9bf18aeb9b56e0c11056fae6a3",
+    order: "0x8000000000000000000000000000069d5bb915bcd46efb1ad5f173abdf",
+    cofactor: 4,
+);
 
-impl BinaryCurve<N, Fq233> for K233 {
-    const A2: Fq233 = Fq233(BinaryPolynomial([0, 0, 0, 0, 0, 0, 0, 0]));
-    const A6: Fq233 = Fq233(BinaryPolynomial([1, 0, 0, 0, 0, 0, 0, 0]));
-    const IDENTITY: ProjectivePoint<N, Fq233, Self> = ProjectivePoint {
-        x: Fq233(BinaryPolynomial([1, 0, 0, 0, 0, 0, 0, 0])),
-        y: Fq233(BinaryPolynomial([0, 0, 0, 0, 0, 0, 0, 0])),
-        z: Fq233(BinaryPolynomial([0, 0, 0, 0, 0, 0, 0, 0])),
-        marker: PhantomData,
-    };
-    const GENERATOR: ProjectivePoint<N, Fq233, Self> = ProjectivePoint {
-        x: Fq233(BinaryPolynomial([
-            1725572810, 2000923818, 1177071851, 1254338503, 3682277809, 1791495317, 2034630957, 251,
-        ])),
-        y: Fq233(BinaryPolynomial([
-            691743371, 1573401319, 2498410245, 2411648772, 520441339, 3254992135, 3793809428, 211,
-        ])),
-        z: Fq233(BinaryPolynomial([1, 0, 0, 0, 0, 0, 0, 0])),
-        marker: PhantomData,
-    };
-    const A6_SQRT: Fq233 = Fq233(BinaryPolynomial([1, 0, 0, 0, 0, 0, 0, 0]));
+pub type K233Point = ProjectivePoint<N, Fq233, K233>;
+pub type K233SigningKey = SigningKey<N, Fq233, K233>;
+pub type K233VerifyingKey = VerifyingKey<N, Fq233, K233>;
+
+// a_2 = 0, so that \mu = -1
+impl Koblitz for K233 {
+    const MU: i64 = -1;
 }
 
+impl KoblitzCurve<N, Fq233> for K233 {}
+
 #[cfg(test)]
 mod tests {
+    use std::marker::PhantomData;
     use std::str::FromStr;
 
     use super::*;
+    use crate::binary_curve::curve::BinaryCurve;
     use crate::binary_curve::curve::ProjectivePoint;
+    use crate::binary_curve::koblitz::{TauNafwCache, TauNafwTable};
+    use crate::binary_curve::koblitz_tau::{Z, ZTau};
+    use crate::binary_field::BinaryField;
+    use crate::scalar_field::ScalarField;
 
     #[test]
     fn test_is_on_curve() {
@@ -41,6 +50,122 @@ mod tests {
         assert!(g.is_on_curve(), "Test for K233 is_on_curve failed!");
     }
 
+    #[test]
+    fn test_generator_order() {
+        // [n - 1]G = -G, i.e. G generates the subgroup of prime order n
+        let g = K233::GENERATOR;
+        let n_minus_one = -Fn233::one();
+        assert_eq!(g * n_minus_one, -g, "Test for K233 generator order failed!");
+        assert_eq!(g * Fn233::zero(), K233::IDENTITY);
+        // scalars are reduced modulo n rather than added with XOR
+        let (u, v) = (Fn233::from(3u64), n_minus_one);
+        assert_eq!(g * (u + v), g * Fn233::from(2u64));
+        assert_eq!(g * u + g * v, g * (u + v));
+    }
+
+    #[test]
+    fn test_frobenius() {
+        // \tau(P) = [\lambda]P, where \lambda is a root of \lambda^2 - \mu * \lambda + 2 = 0 (mod n), \mu = -1
+        let lambda =
+            Fn233::from_hex_string("0x606590ef0a0a0abf8d755a2be31f5449dfff5b430733472d4910444625");
+        assert_eq!(lambda * lambda + lambda + Fn233::from(2u64), Fn233::zero());
+        assert!(K233::A2.is_zero() && K233::MU == -1);
+        let g = K233::GENERATOR;
+        let tau_g = g.frobenius(1);
+        assert!(
+            tau_g.is_on_curve(),
+            "Test for Frobenius endomorphism failed!"
+        );
+        assert_eq!(tau_g, g * lambda, "Test for Frobenius eigenvalue failed!");
+        // \tau^m is the identity map
+        assert_eq!(g.frobenius(233), g);
+        assert_eq!(K233::IDENTITY.frobenius(1), K233::IDENTITY);
+    }
+
+    #[test]
+    fn test_tau_adic_scalar_mul() {
+        let g = K233::GENERATOR;
+        let k =
+            Fn233::from_hex_string("0x7c14c59e6253fa1903f05141fd555fc81a3c974dd2258a62ec3be829a8");
+        let k_z = Z::from(&k.to_biguint());
+        let rho = ZTau::partial_mod(&k_z, 233);
+        let expected = g * k;
+        assert_eq!(
+            g * &rho.tauNAF(),
+            expected,
+            "Test for tauNAF scalar multiplication failed!"
+        );
+        assert_eq!(
+            g * rho,
+            expected,
+            "Test for Z[tau] scalar multiplication failed!"
+        );
+        // unreduced element works as well, only with a longer expansion
+        assert_eq!(g * ZTau::new(k_z, Z::from(0)), expected);
+        assert_eq!(g * ZTau::default(), g.frobenius(1));
+    }
+
+    #[test]
+    fn test_tau_naf_w_scalar_mul() {
+        let g = K233::GENERATOR;
+        let test_data = [
+            "0x7c14c59e6253fa1903f05141fd555fc81a3c974dd2258a62ec3be829a8",
+            "0x3bd4f59063516f81a1621a4d4885e77e0f4693f893b656abe82c4e5c2f",
+            "0x0000000000000000000000000000000000000000000000000000000064",
+        ];
+        for w in 2..7 {
+            let table = TauNafwTable::new(&g, w);
+            assert_eq!(table.points.len(), 1 << (w - 2));
+            assert!(
+                table
+                    .points
+                    .iter()
+                    .all(|p| p.is_affine() && p.is_on_curve())
+            );
+            for k_hex_string in test_data {
+                let k = Fn233::from_hex_string(k_hex_string);
+                assert_eq!(
+                    table.scalar_mul(k),
+                    g * k,
+                    "Test for K233 tauNAF_w scalar multiplication failed!"
+                );
+            }
+            assert_eq!(table.scalar_mul(Fn233::zero()), K233::IDENTITY);
+            assert_eq!(table.scalar_mul(-Fn233::one()), -g);
+        }
+    }
+
+    #[test]
+    fn test_tau_naf_w_cache() {
+        let (g, k) = (K233::GENERATOR, Fn233::from(0x1234u64));
+        let h = g * k;
+        let mut cache = TauNafwCache::new();
+        assert!(cache.is_empty());
+        assert_eq!(cache.scalar_mul(&g, 4, k), h);
+        // the same point in other coordinates and the same window size hit the cached table
+        let g2 = K233Point::new(g.x * g.x, g.y * g.x.squaring(), g.x);
+        assert_eq!(cache.scalar_mul(&g2, 4, k), h);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.scalar_mul(&g, 5, k), h);
+        assert_eq!(cache.scalar_mul(&h, 4, k), h * k);
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.table(&g, 5).w, 5);
+        assert_eq!(cache.len(), 3);
+    }
+
+    #[test]
+    fn test_affine() {
+        let g = K233Point::generator();
+        let (x, y) = g.to_affine().unwrap();
+        assert_eq!(K233Point::from_affine(x, y), g);
+        let h = g + g;
+        let (x, y) = h.to_affine().unwrap();
+        let h_affine = K233Point::from_affine(x, y);
+        assert!(h_affine.is_affine() && h_affine.is_on_curve());
+        assert_eq!(h_affine, h, "Test for K233 affine conversion failed!");
+        assert_eq!(K233Point::identity().to_affine(), None);
+    }
+
     #[test]
     fn test_addition() {
         let test_data = [(
@@ -237,14 +362,16 @@ mod tests {
                     .unwrap(),
                 ),
             ),
+            // [v mod n]G on the SEC 2 base point, where v > n, the expected point is the public key of the private key
+            // v mod n on sect233k1 computed by OpenSSL 3.5
             (
                 (
                     String::from_str(
-                        "0x000000fb7946012d6ac80c95db7b19b14ac3afc74628b0eb7743acaa66da26ca",
+                        "0x0000017232ba853a7e731af129f22ff4149563a419c26bf50a4c9d6eefad6126",
                     )
                     .unwrap(),
                     String::from_str(
-                        "0x000000d3e220f014c2033d071f054dfb8fbed70494eab7055dc832e7293b2a8b",
+                        "0x000001db537dece819b7f70f555a67c427a8cd9bf18aeb9b56e0c11056fae6a3",
                     )
                     .unwrap(),
                     String::from_str(
@@ -258,11 +385,11 @@ mod tests {
                 .unwrap(),
                 (
                     String::from_str(
-                        "0x000001b28c591e7773e37179530ffa59fb2c531c39bd4f1715596cdbd1892568",
+                        "0x000000feb8d0e59e3b4c370588b09610f388a5508c6996afdf467460991b735b",
                     )
                     .unwrap(),
                     String::from_str(
-                        "0x0000005f46e4100332eea099da75d3435cd77ba6be13c06f559cef4ba0d06fa9",
+                        "0x00000033df639a10a1c2cdb8a0915a7a23c94194483e421c8611f23259bff510",
                     )
                     .unwrap(),
                     String::from_str(
@@ -280,7 +407,7 @@ mod tests {
                     z: Fq233::from_hex_string(&u_hex_string.2),
                     marker: PhantomData::<K233>,
                 },
-                Fq233::from_hex_string(&v_hex_string),
+                Fn233::from_hex_string(&v_hex_string),
                 ProjectivePoint {
                     x: Fq233::from_hex_string(&w_expected_hex_string.0),
                     y: Fq233::from_hex_string(&w_expected_hex_string.1),
@@ -296,4 +423,133 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_projective_scalar_mul() {
+        // the ladder starts from the affine coordinates of a projective base point
+        let p = K233::GENERATOR * Fn233::from(0x1234u64) + K233::GENERATOR;
+        assert!(!p.is_affine());
+        let (x, y) = p.to_affine().unwrap();
+        let k = Fn233::from(0x0a5b5a5a5u64);
+        assert_eq!(p * k, K233Point::from_affine(x, y) * k);
+        assert_eq!(p * Fn233::from(2u64), p + p);
+    }
+
+    // equal sequences of traced field operations, conditional swaps and selects for secrets of different weights
+    #[test]
+    fn test_constant_time_ladder() {
+        use crate::binary_field::op_trace::trace;
+        use crate::ecdh::DiffieHellman;
+        // secret scalars of weight 1 up to nearly BITS, and n - 1 whose [k + 1]G = O is selected rather than returned
+        let scalars = [
+            Fn233::one(),
+            Fn233::from(2u64),
+            Fn233::from(0x8000_0000u64),
+            -Fn233::from(2u64),
+            -Fn233::one(),
+            Fn233::from_hex_string("0x0a1b2c3d4e5f60718293a4b5c6d7e8f90112233445566778899aabbccd"),
+        ];
+        // the x-only ladder of Diffie-Hellman and the ladder of ECDSA keys and nonces are branch-free in any
+        // build, scalar multiplication only in the constant-time backend
+        let expected = trace(|| K233::public_x(&scalars[0]));
+        for k in scalars {
+            assert_eq!(
+                trace(|| K233::public_x(&k)),
+                expected,
+                "Test for constant-time ladder failed!"
+            );
+        }
+        let g = K233::GENERATOR;
+        let expected = trace(|| K233::ladder_scalar_mul(&g, scalars[0]));
+        for k in scalars {
+            assert_eq!(
+                trace(|| K233::ladder_scalar_mul(&g, k)),
+                expected,
+                "Test for constant-time ladder failed!"
+            );
+        }
+        #[cfg(feature = "constant-time")]
+        {
+            let expected = trace(|| g * scalars[0]);
+            for k in scalars {
+                assert_eq!(
+                    trace(|| g * k),
+                    expected,
+                    "Test for constant-time ladder failed!"
+                );
+            }
+        }
+    }
+
+    // the fixed-length ladder agrees with the default multiplication, [n - 1]G = -G and [0]G = O included
+    #[test]
+    fn test_ladder_scalar_mul() {
+        let g = K233::GENERATOR;
+        let scalars = [
+            Fn233::zero(),
+            Fn233::one(),
+            -Fn233::one(),
+            -Fn233::from(2u64),
+            Fn233::from_hex_string("0x0a1b2c3d4e5f60718293a4b5c6d7e8f90112233445566778899aabbccd"),
+        ];
+        for k in scalars {
+            assert_eq!(K233::ladder_scalar_mul(&g, k), g * k);
+        }
+        assert_eq!(K233::ladder_scalar_mul(&g, -Fn233::one()), -g);
+        assert!(K233::ladder_scalar_mul(&g, Fn233::zero()).is_identity());
+        assert!(K233::ladder_scalar_mul(&K233::IDENTITY, Fn233::one()).is_identity());
+    }
+
+    #[test]
+    fn test_batch_normalize() {
+        let g = K233::GENERATOR;
+        let mut points = (1..8u64)
+            .map(|k| g * Fn233::from(k) + g)
+            .chain([K233::IDENTITY, g])
+            .collect::<Vec<_>>();
+        let expected = points.clone();
+        ProjectivePoint::batch_normalize(&mut points);
+        assert_eq!(points, expected, "Test for batch normalization failed!");
+        for (p, q) in points.iter().zip(expected.iter()) {
+            match q.to_affine() {
+                Some((x, y)) => assert_eq!((p.x, p.y, p.z), (x, y, Fq233::one())),
+                None => assert!(p.is_identity()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_lazy_reduction() {
+        use crate::binary_field::op_trace::trace;
+        // (products, reductions), which are equal when every product is reduced right away
+        let counts = |t: Vec<&str>| {
+            let products = t.iter().filter(|op| **op == "mul" || **op == "sqr").count();
+            let reductions = t.iter().filter(|op| **op == "reduce").count();
+            (products, reductions)
+        };
+        let g = K233::GENERATOR;
+        let (p, q) = (
+            g * Fn233::from(0x1234u64) + g,
+            g * Fn233::from(0x5678u64) + g,
+        );
+        assert!(!p.is_affine() && !q.is_affine());
+        // reductions saved by summing products before reducing
+        let saved = |t: Vec<&str>| {
+            let (products, reductions) = counts(t);
+            products - reductions
+        };
+        assert_eq!(saved(trace(|| K233::add(&p, &q))), 3);
+        assert_eq!(saved(trace(|| K233::add(&p, &g))), 2);
+        assert_eq!(saved(trace(|| K233::double(&p))), 2);
+        assert_eq!(saved(trace(|| K233::is_on_curve(&p))), 4);
+        assert_eq!(
+            saved(trace(|| K233::mont_add(&(p.x, p.z), &(q.x, q.z), g.x))),
+            1
+        );
+        // a ladder step saves one reduction, i.e. BITS reductions in total
+        let k =
+            Fn233::from_hex_string("0x0a1b2c3d4e5f60718293a4b5c6d7e8f90112233445566778899aabbccd");
+        let (products, reductions) = counts(trace(|| K233::mont_ladder(&k, g.x)));
+        assert_eq!(products - reductions, Fn233::BITS);
+    }
 }
@@ -0,0 +1,106 @@
+/// Instantiation of Binary Curve with K-283, E(\bar{K}): y^2 + xy = x^3 + 1, where \bar{K} = GF(2)[X] / X^283 + X^12 + X^7 + X^5 + 1
+use super::curve::ProjectivePoint;
+use super::koblitz::KoblitzCurve;
+use super::koblitz_tau::Koblitz;
+use crate::binary_field::fq283::{self, Fq283, N};
+use crate::define_binary_curve;
+use crate::scalar_field::fn283::Fn283;
+
+// base point of sect283k1 which generates the subgroup of prime order n
+define_binary_curve!(
+    K283, fq283::Fq283, Fn283,
+    name: "sect283k1",
+    oid: "1.3.132.0.16",
+    a2: "0x0",
+    a6: "0x1",
+    generator_x: "0x0503213f78ca44883f1a3b8162f188e553cd265f23c1567a16876913b0c2ac2458492836",
+    generator_y: "0x01ccda380f1c9e318d90f95d07e5426fe87e45c0e8184698e45962364e34116177dd2259",
+    order: "0x1ffffffffffffffffffffffffffffffffffe9ae2ed07577265dff7f94451e061e163c61",
+    cofactor: 4,
+);
+
+pub type K283Point = ProjectivePoint<N, Fq283, K283>;
+
+// a_2 = 0, so that \mu = -1
+impl Koblitz for K283 {
+    const MU: i64 = -1;
+}
+
+impl KoblitzCurve<N, Fq283> for K283 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary_curve::curve::BinaryCurve;
+    use crate::binary_curve::koblitz::TauNafwTable;
+    use crate::binary_field::BinaryField;
+    use crate::scalar_field::ScalarField;
+
+    #[test]
+    fn test_is_on_curve() {
+        let g = K283::GENERATOR;
+        assert!(g.is_on_curve(), "Test for K283 is_on_curve failed!");
+    }
+
+    #[test]
+    fn test_generator_order() {
+        // [n - 1]G = -G, i.e. G generates the subgroup of prime order n
+        let g = K283::GENERATOR;
+        assert_eq!(
+            g * -Fn283::one(),
+            -g,
+            "Test for K283 generator order failed!"
+        );
+        assert_eq!(g * Fn283::zero(), K283::IDENTITY);
+    }
+
+    #[test]
+    fn test_frobenius() {
+        // \tau(P) = [\lambda]P, where \lambda is a root of \lambda^2 + \lambda + 2 = 0 (mod n), \mu = -1
+        let lambda = Fn283::from_hex_string(
+            "0xd5d05a1b6c5acee76b8ee3f925a57219bcb95212945154588d0415a5b4bb5057f69216",
+        );
+        assert_eq!(lambda * lambda + lambda + Fn283::from(2u64), Fn283::zero());
+        assert!(K283::A2.is_zero() && K283::MU == -1);
+        let g = K283::GENERATOR;
+        assert_eq!(
+            g.frobenius(1),
+            g * lambda,
+            "Test for Frobenius eigenvalue failed!"
+        );
+        // \tau^m is the identity map
+        assert_eq!(g.frobenius(283), g);
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let g = K283::GENERATOR;
+        let k = Fn283::from_hex_string(
+            "0x10eb4695b11b76f2670e0984f0cf267329911da9fbd873580ed55037ea03260d7ef27bb",
+        );
+        let expected = K283Point::from_affine(
+            Fq283::from_hex_string(
+                "0x02b9363c506d2ec7257a8c3e50ba8bc364ac0e169845cb15fd45f5ef7dbf48a638828b1a",
+            ),
+            Fq283::from_hex_string(
+                "0x02f99963b9ae84eb9c97501e57842c5ee892d275f57ab4a66b8c1c88dfd0dbd4439ec15f",
+            ),
+        );
+        assert_eq!(
+            g * k,
+            expected,
+            "Test for K283 scalar multiplication failed!"
+        );
+        assert_eq!(
+            K283::montgomery_scalar_mul(&g, k),
+            expected,
+            "Test for K283 montgomery scalar multiplication failed!"
+        );
+        let table = TauNafwTable::new(&g, 5);
+        assert_eq!(
+            table.scalar_mul(k),
+            expected,
+            "Test for K283 tauNAF_w scalar multiplication failed!"
+        );
+    }
+}
@@ -0,0 +1,106 @@
+/// Instantiation of Binary Curve with K-409, E(\bar{K}): y^2 + xy = x^3 + 1, where \bar{K} = GF(2)[X] / X^409 + X^87 + 1
+use super::curve::ProjectivePoint;
+use super::koblitz::KoblitzCurve;
+use super::koblitz_tau::Koblitz;
+use crate::binary_field::fq409::{self, Fq409, N};
+use crate::define_binary_curve;
+use crate::scalar_field::fn409::Fn409;
+
+// base point of sect409k1 which generates the subgroup of prime order n
+define_binary_curve!(
+    K409, fq409::Fq409, Fn409,
+    name: "sect409k1",
+    oid: "1.3.132.0.36",
+    a2: "0x0",
+    a6: "0x1",
+    generator_x: "0x0060f05f658f49c1ad3ab1890f7184210efd0987e307c84c27accfb8f9f67cc2c460189eb5aaaa62ee222eb1b35540cfe9023746",
+    generator_y: "0x01e369050b7c4e42acba1dacbf04299c3460782f918ea427e6325165e9ea10e3da5f6c42e9c55215aa9ca27a5863ec48d8e0286b",
+    order: "0x7ffffffffffffffffffffffffffffffffffffffffffffffffffe5f83b2d4ea20400ec4557d5ed3e3e7ca5b4b5c83b8e01e5fcf",
+    cofactor: 4,
+);
+
+pub type K409Point = ProjectivePoint<N, Fq409, K409>;
+
+// a_2 = 0, so that \mu = -1
+impl Koblitz for K409 {
+    const MU: i64 = -1;
+}
+
+impl KoblitzCurve<N, Fq409> for K409 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary_curve::curve::BinaryCurve;
+    use crate::binary_curve::koblitz::TauNafwTable;
+    use crate::binary_field::BinaryField;
+    use crate::scalar_field::ScalarField;
+
+    #[test]
+    fn test_is_on_curve() {
+        let g = K409::GENERATOR;
+        assert!(g.is_on_curve(), "Test for K409 is_on_curve failed!");
+    }
+
+    #[test]
+    fn test_generator_order() {
+        // [n - 1]G = -G, i.e. G generates the subgroup of prime order n
+        let g = K409::GENERATOR;
+        assert_eq!(
+            g * -Fn409::one(),
+            -g,
+            "Test for K409 generator order failed!"
+        );
+        assert_eq!(g * Fn409::zero(), K409::IDENTITY);
+    }
+
+    #[test]
+    fn test_frobenius() {
+        // \tau(P) = [\lambda]P, where \lambda is a root of \lambda^2 + \lambda + 2 = 0 (mod n), \mu = -1
+        let lambda = Fn409::from_hex_string(
+            "0x24ef9054eb3a6cf4bdc6ed021f6e5cb8da0c795f913c52ebaa92398d1b7d3d0adb8a34add81800acf7e302a7d250951701d7a4",
+        );
+        assert_eq!(lambda * lambda + lambda + Fn409::from(2u64), Fn409::zero());
+        assert!(K409::A2.is_zero() && K409::MU == -1);
+        let g = K409::GENERATOR;
+        assert_eq!(
+            g.frobenius(1),
+            g * lambda,
+            "Test for Frobenius eigenvalue failed!"
+        );
+        // \tau^m is the identity map
+        assert_eq!(g.frobenius(409), g);
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let g = K409::GENERATOR;
+        let k = Fn409::from_hex_string(
+            "0x8f4f496a881fe148405df6aff8737683b4eb172308da69062206b68fc60bf239805543b51f400e7b6c5586f83f5be91b23a19",
+        );
+        let expected = K409Point::from_affine(
+            Fq409::from_hex_string(
+                "0x00de45526d79c51f22d9c86f94b22298ce4ff1806733764a20e7518275d03307a5f457531142a8dce419e2ea47f3c0547a97f4a4",
+            ),
+            Fq409::from_hex_string(
+                "0x01586497b62b47c66d1bf4b0281088e882c0819bdfdd2bd9603ea822792977a338e85009da571905001f5dfbd89df444b7b75393",
+            ),
+        );
+        assert_eq!(
+            g * k,
+            expected,
+            "Test for K409 scalar multiplication failed!"
+        );
+        assert_eq!(
+            K409::montgomery_scalar_mul(&g, k),
+            expected,
+            "Test for K409 montgomery scalar multiplication failed!"
+        );
+        let table = TauNafwTable::new(&g, 5);
+        assert_eq!(
+            table.scalar_mul(k),
+            expected,
+            "Test for K409 tauNAF_w scalar multiplication failed!"
+        );
+    }
+}
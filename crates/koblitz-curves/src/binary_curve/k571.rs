@@ -0,0 +1,106 @@
+/// Instantiation of Binary Curve with K-571, E(\bar{K}): y^2 + xy = x^3 + 1, where \bar{K} = GF(2)[X] / X^571 + X^10 + X^5 + X^2 + 1
+use super::curve::ProjectivePoint;
+use super::koblitz::KoblitzCurve;
+use super::koblitz_tau::Koblitz;
+use crate::binary_field::fq571::{self, Fq571, N};
+use crate::define_binary_curve;
+use crate::scalar_field::fn571::Fn571;
+
+// base point of sect571k1 which generates the subgroup of prime order n
+define_binary_curve!(
+    K571, fq571::Fq571, Fn571,
+    name: "sect571k1",
+    oid: "1.3.132.0.38",
+    a2: "0x0",
+    a6: "0x1",
+    generator_x: "0x026eb7a859923fbc82189631f8103fe4ac9ca2970012d5d46024804801841ca44370958493b205e647da304db4ceb08cbbd1ba39494776fb988b47174dca88c7e2945283a01c8972",
+    generator_y: "0x0349dc807f4fbf374f4aeade3bca95314dd58cec9f307a54ffc61efc006d8a2c9d4979c0ac44aea74fbebbb9f772aedcb620b01a7ba7af1b320430c8591984f601cd4c143ef1c7a3",
+    order: "0x20000000000000000000000000000000000000000000000000000000000000000000000131850e1f19a63e4b391a8db917f4138b630d84be5d639381e91deb45cfe778f637c1001",
+    cofactor: 4,
+);
+
+pub type K571Point = ProjectivePoint<N, Fq571, K571>;
+
+// a_2 = 0, so that \mu = -1
+impl Koblitz for K571 {
+    const MU: i64 = -1;
+}
+
+impl KoblitzCurve<N, Fq571> for K571 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary_curve::curve::BinaryCurve;
+    use crate::binary_curve::koblitz::TauNafwTable;
+    use crate::binary_field::BinaryField;
+    use crate::scalar_field::ScalarField;
+
+    #[test]
+    fn test_is_on_curve() {
+        let g = K571::GENERATOR;
+        assert!(g.is_on_curve(), "Test for K571 is_on_curve failed!");
+    }
+
+    #[test]
+    fn test_generator_order() {
+        // [n - 1]G = -G, i.e. G generates the subgroup of prime order n
+        let g = K571::GENERATOR;
+        assert_eq!(
+            g * -Fn571::one(),
+            -g,
+            "Test for K571 generator order failed!"
+        );
+        assert_eq!(g * Fn571::zero(), K571::IDENTITY);
+    }
+
+    #[test]
+    fn test_frobenius() {
+        // \tau(P) = [\lambda]P, where \lambda is a root of \lambda^2 + \lambda + 2 = 0 (mod n), \mu = -1
+        let lambda = Fn571::from_hex_string(
+            "0x1cc6c27e62f3e0ddf5ea7eb1ab1cc4d0da631c0d70a969aa14b035085b31511f5a9745520cba528e2d1e647f4f708d39fba0c3be4e35543821344d1662727bd2d59dbc05e6853b1",
+        );
+        assert_eq!(lambda * lambda + lambda + Fn571::from(2u64), Fn571::zero());
+        assert!(K571::A2.is_zero() && K571::MU == -1);
+        let g = K571::GENERATOR;
+        assert_eq!(
+            g.frobenius(1),
+            g * lambda,
+            "Test for Frobenius eigenvalue failed!"
+        );
+        // \tau^m is the identity map
+        assert_eq!(g.frobenius(571), g);
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let g = K571::GENERATOR;
+        let k = Fn571::from_hex_string(
+            "0xacff5afa3fbdc187deb1272db6429ef6d35c95e6d4cd8a94454f8c0eb65db820339191a9266c7f0d2eafe16bb59681c9aefeea9e45e7ce1d7488cfddd20fb842217aa9271112a4",
+        );
+        let expected = K571Point::from_affine(
+            Fq571::from_hex_string(
+                "0x0089302c1f7f01beaa083b551e66ce7d00ee1af1aaa4f076441ed7ee95dc22645b57a9bb06210e60ffac89be17a97797acb6b3b1e2b68ca0754bba41a43165368267aaf56845e80e",
+            ),
+            Fq571::from_hex_string(
+                "0x033f6370d4017d1bc69861933f3c88e7058c8aeadb522299b339886aa3266f760174c7c8d86912919f2544340a4723f5b764c090827a37c39355bf69a2803aed158daf056970f27f",
+            ),
+        );
+        assert_eq!(
+            g * k,
+            expected,
+            "Test for K571 scalar multiplication failed!"
+        );
+        assert_eq!(
+            K571::montgomery_scalar_mul(&g, k),
+            expected,
+            "Test for K571 montgomery scalar multiplication failed!"
+        );
+        let table = TauNafwTable::new(&g, 5);
+        assert_eq!(
+            table.scalar_mul(k),
+            expected,
+            "Test for K571 tauNAF_w scalar multiplication failed!"
+        );
+    }
+}
@@ -0,0 +1,141 @@
+/// Koblitz curves, i.e. binary curves with a_2, a_6 in GF(2), on which Frobenius map \tau is an endomorphism
+/// so that scalar multiplication can walk \tau-adic digits with cheap squarings instead of doublings
+use super::curve::{BinaryCurve, ProjectivePoint};
+use super::koblitz_tau::{Koblitz, Tau, Z, ZTau};
+use crate::binary_field::BinaryField;
+use crate::scalar_field::ScalarField;
+
+pub trait KoblitzCurve<const N: usize, Field: BinaryField<N>>:
+    BinaryCurve<N, Field> + Koblitz
+{
+    // degree of binary field, \tau^M is the identity map
+    const M: usize = Field::M;
+
+    // reduce scalar k to \rho = k (mod \delta) and expand \rho with tauNAF_w digits, using the residues and h_w cached
+    // in the table
+    fn tau_naf_w_digits(scalar: &Self::Scalar, table: &TauNafwTable<N, Field, Self>) -> Vec<i64> {
+        let k = Z::from(&scalar.to_biguint());
+        ZTau::<Self>::partial_mod(&k, Self::M).tauNAFw_digits_with(
+            table.w,
+            &table.h_w,
+            &table.u_mod_tau_w,
+        )
+    }
+
+    // windowed tauNAF scalar multiplication with points precomputed in the table
+    fn tau_naf_w_scalar_mul(
+        table: &TauNafwTable<N, Field, Self>,
+        scalar: Self::Scalar,
+    ) -> ProjectivePoint<N, Field, Self> {
+        let digits = Self::tau_naf_w_digits(&scalar, table);
+        let mut result = Self::IDENTITY;
+        for u in digits.iter().rev() {
+            result = result.frobenius(1);
+            if *u > 0 {
+                result = result + table.points[(u / 2) as usize];
+            } else if *u < 0 {
+                result = result - table.points[(-u / 2) as usize];
+            }
+        }
+        result
+    }
+}
+
+// \alpha_u * P for odd u in [1, 2^{w - 1}), where \alpha_u = u mod \tau^w,
+// a table is bound to one point and one window size, build it once and reuse it for many scalars, or let
+// TauNafwCache keep one table per point and window size
+#[derive(Debug, Clone)]
+pub struct TauNafwTable<const N: usize, Field: BinaryField<N>, Curve: KoblitzCurve<N, Field>> {
+    pub w: usize,
+    pub base: ProjectivePoint<N, Field, Curve>,
+    pub points: Vec<ProjectivePoint<N, Field, Curve>>,
+    // u mod \tau^w for odd u and h_w, which only depend on the window size
+    pub u_mod_tau_w: Vec<ZTau<Curve>>,
+    pub h_w: Z,
+}
+
+impl<const N: usize, Field: BinaryField<N>, Curve: KoblitzCurve<N, Field>>
+    TauNafwTable<N, Field, Curve>
+{
+    pub fn new(base: &ProjectivePoint<N, Field, Curve>, w: usize) -> Self {
+        assert!(w >= 2, "Window size should be at least 2!");
+        let (u_mod_tau_w, alpha_u) = ZTau::<Curve>::precomputed_table(w);
+        // affine points so that the additions in the main loop are mixed ones, normalized with a single inversion
+        let mut points = alpha_u
+            .iter()
+            .map(|alpha| *base * alpha)
+            .collect::<Vec<_>>();
+        ProjectivePoint::batch_normalize(&mut points);
+        Self {
+            w,
+            base: *base,
+            points,
+            u_mod_tau_w,
+            h_w: ZTau::<Curve>::h_w(w),
+        }
+    }
+
+    pub fn scalar_mul(&self, scalar: Curve::Scalar) -> ProjectivePoint<N, Field, Curve> {
+        Curve::tau_naf_w_scalar_mul(self, scalar)
+    }
+}
+
+// tables cached per point and window size, a table is built the first time a pair (P, w) is used and reused afterwards
+#[derive(Debug, Clone)]
+pub struct TauNafwCache<const N: usize, Field: BinaryField<N>, Curve: KoblitzCurve<N, Field>> {
+    tables: Vec<TauNafwTable<N, Field, Curve>>,
+}
+
+impl<const N: usize, Field: BinaryField<N>, Curve: KoblitzCurve<N, Field>> Default
+    for TauNafwCache<N, Field, Curve>
+{
+    fn default() -> Self {
+        Self { tables: vec![] }
+    }
+}
+
+impl<const N: usize, Field: BinaryField<N>, Curve: KoblitzCurve<N, Field>>
+    TauNafwCache<N, Field, Curve>
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // the table of (base, w), built on a cache miss
+    pub fn table(
+        &mut self,
+        base: &ProjectivePoint<N, Field, Curve>,
+        w: usize,
+    ) -> &TauNafwTable<N, Field, Curve> {
+        let i = match self
+            .tables
+            .iter()
+            .position(|table| table.w == w && table.base == *base)
+        {
+            Some(i) => i,
+            None => {
+                self.tables.push(TauNafwTable::new(base, w));
+                self.tables.len() - 1
+            }
+        };
+        &self.tables[i]
+    }
+
+    // number of cached tables
+    pub fn len(&self) -> usize {
+        self.tables.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+
+    pub fn scalar_mul(
+        &mut self,
+        base: &ProjectivePoint<N, Field, Curve>,
+        w: usize,
+        scalar: Curve::Scalar,
+    ) -> ProjectivePoint<N, Field, Curve> {
+        self.table(base, w).scalar_mul(scalar)
+    }
+}
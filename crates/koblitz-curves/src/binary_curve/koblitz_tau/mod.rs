@@ -4,6 +4,27 @@ pub mod z_tau;
 
 pub use self::r_tau::{R, RTau};
 pub use self::z_tau::{Z, ZTau, ZTauExpansion};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+// implement binary operators for both owned and borrowed operands of a newtype over big numbers
+macro_rules! impl_big_ops {
+    ($T:ident, $($Op:ident, $op:ident);+) => {$(
+        impl std::ops::$Op<$T> for $T {
+            type Output = $T;
+            fn $op(self, rhs: $T) -> Self::Output {
+                $T(std::ops::$Op::$op(self.0, rhs.0))
+            }
+        }
+        impl<'a> std::ops::$Op<&'a $T> for &'a $T {
+            type Output = $T;
+            fn $op(self, rhs: &'a $T) -> Self::Output {
+                $T(std::ops::$Op::$op(&self.0, &rhs.0))
+            }
+        }
+    )+};
+}
+pub(crate) use impl_big_ops;
 
 // N(x) = \prod_i x * x_i, where x_i are all the conjugates of x
 // For example, if x is a complex number, then there are only two conjugative numbers
@@ -11,17 +32,21 @@ pub trait Norm<T> {
     fn norm(&self) -> T;
 }
 
-// Characteristic polynomial of Frobenius Endomorphism for binary curve K-233: \tau^2 - \mu * \tau + 2
-// \mu = (-1)^{1 - a_2}, \mu = -1 when a_2 = 0 in K-233 curve
+// Characteristic polynomial of Frobenius Endomorphism for Koblitz curve: \tau^2 - \mu * \tau + 2
+// \mu = (-1)^{1 - a_2}, i.e. \mu = -1 when a_2 = 0 like K-233, and \mu = 1 when a_2 = 1 like K-163
+pub trait Koblitz: Debug + Clone + Copy + Eq + PartialEq {
+    const MU: i64;
+}
+
+// Z[\tau] and R[\tau] of a Koblitz curve
 pub trait Tau: Sized {
-    const MU: Z = Z(-1);
-    const CHAR: Z = Z(2);
+    type Curve: Koblitz;
+    const MU: i64 = <Self::Curve as Koblitz>::MU;
+    const CHAR: i64 = 2;
     fn one() -> Self;
     fn zero() -> Self;
     // map to its conjugative representation, for example, a + b * \bar{\tau} = (a + b * \mu) - b * \tau, and vice veras
     fn automorphism(&self) -> Self;
-    // map \phi_w: Z[\tau] -> Z[2^w] with a kernel element h_w, i.e. \phi_w(h_w) = 0 (mod 2^w)
-    fn isomorphism(&self, hw: Z) -> Z;
     // Z[\tau] -> Z[\bar{tau}] -> Z[\tau]
     // conjugative object, i.e. a + b * \bar{\tau} -> a + b * \tau, which is can map to Self with automorphism
     fn conjugate(&self) -> Self {
@@ -29,39 +54,37 @@ pub trait Tau: Sized {
     }
     //////////////////////////////////////// For Window-based Tau-adic Expansion
     // \tau^w = U_w * \tau - U_{w - 1} * BIAS, where w is the window size
-    fn pow(w: usize) -> ZTau {
-        assert!(w < 64, "Degree is too big!");
+    fn pow(w: usize) -> ZTau<Self::Curve> {
         if w == 0 {
             return ZTau::one();
         }
         if w == 1 {
             return ZTau::default();
         }
-        let lucas_seq = LucasSequence::new(Z(0), Z(1)).n_steps(w - 1);
+        let lucas_seq = LucasSequence::<Self::Curve>::new(Z::from(0), Z::from(1)).n_steps(w - 1);
         ZTau::from(&lucas_seq)
     }
     // hw = 2 * U_{w - 1} * U_w^{-1}, hw is in the kernel of map \phi_w: Z[\tau] -> Z[2^w], i.e. \phi_w(h_w) = 0 (mod 2^w)
     // for example, regarding a + b * \tau + c * \tau^2 + ... \in Z[\tau], we can map it to a + b * hw + c * hw^2 + ... (mod 2^w) = 0
     fn h_w(w: usize) -> Z {
-        let modulus = Z(1 << w);
-        let lucas_seq = LucasSequence::new(Z(0), Z(1)).n_steps(w - 1);
-        let u_w_minus_1 = lucas_seq.u0;
-        let u_w_inv = lucas_seq.u1.inv_mod(modulus);
-        (Z(2) * u_w_minus_1 * u_w_inv).reduce(modulus)
+        let modulus = Z::pow2(w);
+        let lucas_seq = LucasSequence::<Self::Curve>::new(Z::from(0), Z::from(1)).n_steps(w - 1);
+        let u_w_inv = lucas_seq.u1.inv_mod(&modulus);
+        (Z::from(2) * lucas_seq.u0 * u_w_inv).reduce(&modulus)
     }
     // u (mod \tau^w), \alpha_u = tauNAF(u mod \tau^w)
-    fn precomputed_table(w: usize) -> (Vec<ZTau>, Vec<ZTauExpansion>) {
+    fn precomputed_table(w: usize) -> (Vec<ZTau<Self::Curve>>, Vec<ZTauExpansion>) {
         let tau_w = Self::pow(w);
-        let u_mod_tau_w = (1..(1 << (w - 1)))
+        let u_mod_tau_w = (1..(1i64 << (w - 1)))
             .step_by(2)
-            .map(|u| ZTau::new(Z(u as i64), Z(0)).reduce(tau_w))
+            .map(|u| ZTau::new(Z::from(u), Z::from(0)).reduce(&tau_w))
             .collect::<Vec<_>>();
         let alpha_u = u_mod_tau_w.iter().map(|v| v.tauNAF()).collect::<Vec<_>>();
         (u_mod_tau_w, alpha_u)
     }
     // \delta = (\tau^M - 1) / (\tau - 1), where M is the degree of binary field
-    fn delta(d: usize) -> ZTau {
-        let denominator = ZTau::pow(d) - ZTau::one();
+    fn delta(d: usize) -> ZTau<Self::Curve> {
+        let denominator = Self::pow(d) - ZTau::one();
         let nominator = ZTau::default() - ZTau::one();
         let (delta, remainder) = denominator / nominator;
         assert_eq!(remainder, ZTau::zero(), "Computing Delta failed!");
@@ -70,21 +93,26 @@ pub trait Tau: Sized {
 }
 
 // refer to "Handbook of Elliptic and Hyperelliptic Curve Cryptography", equation 15.4
-#[derive(Copy, Clone, Debug)]
-pub struct LucasSequence {
+#[derive(Clone, Debug)]
+pub struct LucasSequence<K: Koblitz> {
     u0: Z,
     u1: Z,
+    marker: PhantomData<K>,
 }
-impl LucasSequence {
+impl<K: Koblitz> LucasSequence<K> {
     pub fn new(u0: Z, u1: Z) -> Self {
-        Self { u0, u1 }
-    }
-    fn next(&self) -> Self {
         Self {
-            u0: self.u1,
-            u1: ZTau::MU * self.u1 - ZTau::CHAR * self.u0,
+            u0,
+            u1,
+            marker: PhantomData,
         }
     }
+    fn next(&self) -> Self {
+        Self::new(
+            self.u1.clone(),
+            &Z::from(K::MU) * &self.u1 - &Z::from(2) * &self.u0,
+        )
+    }
     pub fn n_steps(&self, n: usize) -> Self {
         let mut result = self.clone();
         for _ in 0..n {
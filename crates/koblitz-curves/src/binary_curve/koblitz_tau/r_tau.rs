@@ -1,33 +1,11 @@
-use super::{Norm, Tau, Z, ZTau};
-use num_rational::Rational64;
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use super::{Koblitz, Norm, Tau, Z, ZTau, impl_big_ops};
+use num_rational::BigRational;
+use std::marker::PhantomData;
+use std::ops::Neg;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Ord, PartialOrd)]
-pub struct R(pub Rational64);
-impl Add<Self> for R {
-    type Output = Self;
-    fn add(self, rhs: Self) -> Self::Output {
-        Self(self.0 + rhs.0)
-    }
-}
-impl Sub<Self> for R {
-    type Output = Self;
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self(self.0 - rhs.0)
-    }
-}
-impl Mul<Self> for R {
-    type Output = Self;
-    fn mul(self, rhs: Self) -> Self::Output {
-        Self(self.0 * rhs.0)
-    }
-}
-impl Div<Self> for R {
-    type Output = Self;
-    fn div(self, rhs: Self) -> Self::Output {
-        Self(self.0 / rhs.0)
-    }
-}
+#[derive(Clone, Debug, Eq, PartialEq, Default, Ord, PartialOrd)]
+pub struct R(pub BigRational);
+impl_big_ops!(R, Add, add; Sub, sub; Mul, mul; Div, div);
 impl Neg for R {
     type Output = Self;
     fn neg(self) -> Self::Output {
@@ -37,59 +15,91 @@ impl Neg for R {
 
 impl From<&Z> for R {
     fn from(v: &Z) -> Self {
-        R(Rational64::new(v.0, 1))
+        R(BigRational::from_integer(v.0.clone()))
     }
 }
 
 impl From<&(Z, Z)> for R {
     fn from(v: &(Z, Z)) -> Self {
-        R(Rational64::new(v.0.0, v.1.0))
+        R(BigRational::new(v.0.0.clone(), v.1.0.clone()))
     }
 }
 
-// Rational ring in terms of characteristic polynomial of K-233 curve, R[\tau] = R[X] / \tau^2 - \mu * \tau + 2
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct RTau {
+// Rational ring in terms of characteristic polynomial of Koblitz curve, R[\tau] = R[X] / \tau^2 - \mu * \tau + 2
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RTau<K: Koblitz> {
     pub a0: R,
     pub a1: R,
+    pub marker: PhantomData<K>,
 }
 
-impl RTau {
+impl<K: Koblitz> RTau<K> {
     pub fn new(a0: R, a1: R) -> Self {
-        Self { a0, a1 }
+        Self {
+            a0,
+            a1,
+            marker: PhantomData,
+        }
     }
 }
 
 // Norm of R[\tau]
-impl Norm<R> for RTau {
+impl<K: Koblitz> Norm<R> for RTau<K> {
     fn norm(&self) -> R {
-        let (a0_square, a0_mul_a1, a1_square) =
-            (self.a0 * self.a0, self.a0 * self.a1, self.a1 * self.a1);
-        a0_square + a0_mul_a1 * R::from(&Self::MU) + R::from(&Self::CHAR) * a1_square
+        let (a0_square, a0_mul_a1, a1_square) = (
+            &self.a0 * &self.a0,
+            &self.a0 * &self.a1,
+            &self.a1 * &self.a1,
+        );
+        a0_square
+            + a0_mul_a1 * R::from(&Z::from(Self::MU))
+            + R::from(&Z::from(Self::CHAR)) * a1_square
     }
 }
 
-impl From<&ZTau> for RTau {
-    fn from(zt: &ZTau) -> Self {
+impl<K: Koblitz> From<&ZTau<K>> for RTau<K> {
+    fn from(zt: &ZTau<K>) -> Self {
         RTau::new(R::from(&zt.a0), R::from(&zt.a1))
     }
 }
 
-impl Tau for RTau {
+impl<K: Koblitz> Tau for RTau<K> {
+    type Curve = K;
     fn one() -> Self {
         Self::from(&ZTau::one())
     }
     fn zero() -> Self {
         Self::from(&ZTau::zero())
     }
+    // R[\bar{\tau}] -> R[\tau]
     fn automorphism(&self) -> Self {
-        unimplemented!()
+        Self::new(
+            &self.a0 + &(&R::from(&Z::from(Self::MU)) * &self.a1),
+            -self.a1.clone(),
+        )
     }
-    fn isomorphism(&self, _: Z) -> Z {
-        unimplemented!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary_curve::{k163::K163, k233::K233};
+
+    fn conjugate<K: Koblitz>() {
+        let x = RTau::<K>::new(
+            R::from(&(Z::from(7), Z::from(3))),
+            R::from(&(Z::from(-5), Z::from(4))),
+        );
+        assert_eq!(x.conjugate().conjugate(), x);
+        // conjugation commutes with the embedding Z[\tau] -> R[\tau] and keeps the norm
+        let z = ZTau::<K>::new(Z::from(12345), Z::from(-678));
+        assert_eq!(RTau::from(&z).conjugate(), RTau::from(&z.conjugate()));
+        assert_eq!(x.conjugate().norm(), x.norm());
     }
-    fn conjugate(&self) -> Self {
-        unimplemented!()
+
+    #[test]
+    fn test_conjugate() {
+        conjugate::<K233>();
+        conjugate::<K163>();
     }
 }
-/////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1,131 +1,150 @@
-use super::{LucasSequence, Norm, R, RTau, Tau};
-use num_rational::Rational64;
+/////////////////////////////////////////////////////////////////////////////////////////////////////
+use super::{Koblitz, LucasSequence, Norm, R, RTau, Tau, impl_big_ops};
+use crate::error::Error;
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use std::marker::PhantomData;
 use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Rem, Sub};
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Default, Ord, PartialOrd)]
-pub struct Z(pub i64);
+// number of bits C kept after the point when approximating k / \delta in partial reduction
+pub const PARTIAL_MOD_PRECISION: usize = 16;
+
+// arbitrary-precision integer, so that tau-adic expansions of real scalars never overflow
+#[derive(Clone, Debug, Eq, PartialEq, Default, Ord, PartialOrd, Hash)]
+pub struct Z(pub BigInt);
+impl_big_ops!(Z, Add, add; Sub, sub; Mul, mul; Div, div);
+
+impl From<i64> for Z {
+    fn from(v: i64) -> Self {
+        Z(BigInt::from(v))
+    }
+}
+
+impl From<BigInt> for Z {
+    fn from(v: BigInt) -> Self {
+        Z(v)
+    }
+}
+
+impl From<&BigUint> for Z {
+    fn from(v: &BigUint) -> Self {
+        Z(BigInt::from(v.clone()))
+    }
+}
 
 impl From<Z> for usize {
     fn from(v: Z) -> Self {
-        v.0.abs() as usize
+        v.0.abs().to_usize().expect("Integer is too big for usize!")
     }
 }
 
 impl Z {
-    fn signum(&self) -> Self {
+    // 2^w
+    pub fn pow2(w: usize) -> Self {
+        Z(BigInt::one() << w)
+    }
+
+    pub fn signum(&self) -> Self {
         Z(self.0.signum())
     }
 
-    fn abs(&self) -> Self {
+    pub fn abs(&self) -> Self {
         Z(self.0.abs())
     }
 
     // abs(x) = +1 or -1
-    fn is_odd(&self) -> bool {
-        (self.0 % 2).abs() == 1
+    pub fn is_odd(&self) -> bool {
+        self.0.is_odd()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    // number of bits of the absolute value
+    pub fn bits(&self) -> usize {
+        self.0.bits() as usize
     }
 
     // reduce to a postive integer with a modulus
-    pub fn reduce(&self, modulus: Self) -> Self {
-        let mut r = self.0 % modulus.0;
-        if r < 0 {
-            r = r + modulus.0;
-        }
-        Self(r)
+    pub fn reduce(&self, modulus: &Self) -> Self {
+        Z(self.0.mod_floor(&modulus.0))
+    }
+
+    // panicking counterpart of try_inv_mod
+    pub fn inv_mod(&self, modulus: &Self) -> Self {
+        self.try_inv_mod(modulus)
+            .expect("Integer is not invertible modulo the modulus")
     }
-    // !!! Naitve implementation of inverse mod a modulus
-    pub fn inv_mod(&self, modulus: Self) -> Self {
+
+    // inverse mod a positive modulus with extended Euclidean algorithm
+    pub fn try_inv_mod(&self, modulus: &Self) -> Result<Self, Error> {
+        if !modulus.0.is_positive() {
+            return Err(Error::NotInvertible);
+        }
         let r = self.reduce(modulus);
-        assert!(r.is_odd(), "only odd integers have inverses mod 2^k");
-        let mut s = Z(1);
-        let mut result = Z(0);
-        while s < modulus {
-            let v = s * modulus + Z(1);
-            if v.reduce(r) == Z(0) {
-                result = (v / r).reduce(modulus);
-            }
-            s = s + Z(1);
+        let gcd = r.0.extended_gcd(&modulus.0);
+        if !gcd.gcd.is_one() {
+            return Err(Error::NotInvertible);
         }
-        result
+        Ok(Z(gcd.x).reduce(modulus))
     }
 }
 
 impl From<&R> for Z {
     // Tau-friendly round off algorithm
     fn from(v: &R) -> Self {
-        let half = Rational64::new(1, 2);
-        if v.0.numer().signum() * v.0.denom().signum() > 0 {
-            Z(((v.0 - half).ceil()).to_integer())
+        let half = BigRational::new(BigInt::one(), BigInt::from(2));
+        if v.0.is_positive() {
+            Z((&v.0 - half).ceil().to_integer())
         } else {
-            Z((v.0 + half).floor().to_integer())
+            Z((&v.0 + half).floor().to_integer())
         }
     }
 }
 
-impl Add<Self> for Z {
-    type Output = Self;
-    fn add(self, rhs: Self) -> Self::Output {
-        Self(self.0 + rhs.0)
-    }
-}
-impl Sub<Self> for Z {
-    type Output = Self;
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self(self.0 - rhs.0)
-    }
-}
-impl Mul<Self> for Z {
-    type Output = Self;
-    fn mul(self, rhs: Self) -> Self::Output {
-        Self(self.0 * rhs.0)
-    }
-}
-impl Div<Self> for Z {
-    type Output = Self;
-    fn div(self, rhs: Self) -> Self::Output {
-        Self(self.0 / rhs.0)
-    }
-}
 impl Neg for Z {
     type Output = Self;
     fn neg(self) -> Self::Output {
         Self(-self.0)
     }
 }
+// centered residue in [-modulus / 2, modulus / 2), where modulus is a power of 2
 impl Rem for Z {
     type Output = Self;
     fn rem(self, modulus: Self) -> Self::Output {
-        assert!((modulus.0 % 2 == 0) && (modulus.0 > 0));
-        let (m, m_half) = (modulus.0, modulus.0 >> 1);
-        let mut result = self.0 % m;
-        if result > m_half - 1 {
-            result -= m;
-        } else if result < -m_half {
-            result += m;
+        assert!(modulus.0.is_even() && modulus.0.is_positive());
+        let m_half = &modulus.0 >> 1;
+        let mut result = self.0.mod_floor(&modulus.0);
+        if result >= m_half {
+            result -= &modulus.0;
         }
         Z(result)
     }
 }
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////// Integer tau expansion
-// Integer ring in terms of characteristic polynomial of K-233 curve, Z[\tau] = Z / \tau^2 - \mu * \tau + 2
-#[derive(Clone, Copy, Eq, PartialEq, Debug)]
-pub struct ZTau {
+// Integer ring in terms of characteristic polynomial of Koblitz curve, Z[\tau] = Z / \tau^2 - \mu * \tau + 2
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ZTau<K: Koblitz> {
     pub a0: Z,
     pub a1: Z,
+    pub marker: PhantomData<K>,
 }
 
-impl Default for ZTau {
+impl<K: Koblitz> Default for ZTau<K> {
     fn default() -> Self {
-        Self::new(Z(0), Z(1))
+        Self::new(Z::from(0), Z::from(1))
     }
 }
 
-impl ZTau {
+impl<K: Koblitz> ZTau<K> {
     pub fn exp(&self, times: usize) -> Self {
-        let mut result = ZTau::one();
+        let mut result = Self::one();
         for _ in 0..times {
-            result = result * *self;
+            result = &result * self;
         }
         result
     }
@@ -135,27 +154,39 @@ impl ZTau {
     }
 
     pub fn new(a0: Z, a1: Z) -> Self {
-        Self { a0, a1 }
+        Self {
+            a0,
+            a1,
+            marker: PhantomData,
+        }
     }
 
     pub fn is_zero(&self) -> bool {
-        *self == Self::zero()
+        self.a0.is_zero() && self.a1.is_zero()
+    }
+
+    // map \phi_w: Z[\tau] -> Z[2^w] with a kernel element h_w, i.e. \phi_w(h_w) = 0 (mod 2^w)
+    // only defined on Z[\tau], since rationals of R[\tau] may have even denominators
+    pub fn isomorphism(&self, h_w: &Z) -> Z {
+        &self.a0 + &(&self.a1 * h_w)
     }
 
     // reduce with a modulus, usually we use \tau^w
-    pub fn reduce(&self, modulus: Self) -> Self {
-        (*self / modulus).1
+    pub fn reduce(&self, modulus: &Self) -> Self {
+        (self / modulus).1
     }
 
     // convert tauNAF expansion to Z[\tau]
-    pub fn tauNAF_reverse(data: &ZTauExpansion) -> ZTau {
-        let mut result = ZTau::zero();
+    pub fn tauNAF_reverse(data: &ZTauExpansion) -> Self {
+        let (one, minus_one) = (Z::from(1), Z::from(-1));
+        let mut result = Self::zero();
         for w in (0..data.len()).rev() {
-            match data[w] {
-                Z(1) => result = result + ZTau::pow(w),
-                Z(-1) => result = result - ZTau::pow(w),
-                Z(0) => {}
-                _ => unreachable!(),
+            if data[w] == one {
+                result = result + Self::pow(w);
+            } else if data[w] == minus_one {
+                result = result - Self::pow(w);
+            } else {
+                assert!(data[w].is_zero(), "Invalid digit of tauNAF expansion!");
             }
         }
         result
@@ -163,201 +194,290 @@ impl ZTau {
 
     // convert Z[\tau] to tauNAF expansion
     pub fn tauNAF(&self) -> ZTauExpansion {
+        let (two, four) = (Z::from(2), Z::from(4));
         let mut result = vec![];
         let mut n = self.clone();
-        while n.is_zero() == false {
+        while !n.is_zero() {
             let ri = if n.is_odd() {
                 // ensure r_i = +1 or -1, since (n0 - 2 * n1) % 4 = 1 or 3
-                let residual = Z(2) - (n.a0 - Z(2) * n.a1).reduce(Z(4));
-                n = n - ZTau::new(residual, Z(0));
+                let residual = &two - &(&n.a0 - &(&two * &n.a1)).reduce(&four);
+                n.a0 = &n.a0 - &residual;
                 residual
             } else {
-                Z(0)
+                Z::from(0)
             };
             result.push(ri);
             // now n or n0 is a even number, then we can right shift it one time, i.e. n / \tau
-            (n, _) = n / ZTau::default();
+            n = n.div_tau();
         }
         ZTauExpansion(result)
     }
     // convert Z[\tau] to tauNAF_w expansion
     pub fn tauNAFw(&self, w: usize) -> Vec<ZTauExpansion> {
-        let h_w = Self::h_w(w);
-        let modulus = Z(1 << w);
         let (u_mod_tau_w, alpha_u) = Self::precomputed_table(w);
+        self.tauNAFw_digits_with(w, &Self::h_w(w), &u_mod_tau_w)
+            .into_iter()
+            .map(|u| match u {
+                0 => ZTauExpansion(vec![]),
+                u if u > 0 => alpha_u[(u / 2) as usize].clone(),
+                u => -alpha_u[(-u / 2) as usize].clone(),
+            })
+            .collect::<Vec<_>>()
+    }
+
+    // signed odd residues u_i of tauNAF_w expansion, i.e. \sum_i sign(u_i) * \alpha_{|u_i|} * \tau^i,
+    // where \alpha_u = u mod \tau^w is stored at index |u_i| / 2 of the precomputed table
+    pub fn tauNAFw_digits(&self, w: usize) -> Vec<i64> {
+        let (u_mod_tau_w, _) = Self::precomputed_table(w);
+        self.tauNAFw_digits_with(w, &Self::h_w(w), &u_mod_tau_w)
+    }
+
+    // tauNAFw_digits with h_w and the residues u mod \tau^w computed once per window size, e.g. by TauNafwTable
+    pub fn tauNAFw_digits_with(&self, w: usize, h_w: &Z, u_mod_tau_w: &[Self]) -> Vec<i64> {
+        let modulus = Z::pow2(w);
         let mut result = vec![];
         let mut t = self.clone();
-        while t.is_zero() == false {
+        while !t.is_zero() {
             let ri = if t.is_odd() {
                 // residual must be an even number, then we can remove it from T, notice the sign of this residual
-                let u = t.isomorphism(h_w) % modulus;
-                let u_index = u / Z(2);
-                let residual = u_mod_tau_w[usize::from(u_index)];
-                t = if u > Z(0) { t - residual } else { t + residual };
-                if u > Z(0) {
-                    alpha_u[usize::from(u_index)].clone()
+                let u = t.isomorphism(h_w) % modulus.clone();
+                let u_index = usize::from(&u / &Z::from(2));
+                let residual = &u_mod_tau_w[u_index];
+                if u.0.is_positive() {
+                    t = &t - residual;
                 } else {
-                    -alpha_u[usize::from(u_index)].clone()
+                    t = &t + residual;
                 }
+                u.0.to_i64().expect("Window size is too big!")
             } else {
-                ZTauExpansion(vec![])
+                0
             };
             result.push(ri);
             // now t is a even, then right shift one time, i.e. t = t / \tau
-            (t, _) = t / ZTau::default();
+            t = t.div_tau();
         }
         result
     }
+
+    // Solinas' partial reduction, Algorithm 3.74 in "Guide to Elliptic Curve Cryptography"
+    // find \rho = k - q * \delta where \delta = (\tau^m - 1) / (\tau - 1) and q is the rounding of k / \delta,
+    // N(\delta) = n, so that N(\rho) is close to n and tauNAF(\rho) has about m digits instead of 2m.
+    // The rounding is done with integer shifts only, i.e. \lambda_i = s_i * k / n is approximated with C bits after the point
+    pub fn partial_mod(k: &Z, m: usize) -> Self {
+        assert!(m % 2 == 1, "Degree of binary field should be odd!");
+        let a = ((1 + Self::MU) / 2) as usize;
+        let C = PARTIAL_MOD_PRECISION;
+        assert!(a + (m - 9) / 2 >= C, "Degree of binary field is too small!");
+        // \delta = d0 + d1 * \tau, \bar{\delta} = s0 + s1 * \tau
+        let delta = Self::delta(m);
+        let (s0, s1) = (
+            &delta.a0 + &(&Z::from(Self::MU) * &delta.a1),
+            -delta.a1.clone(),
+        );
+        // V_m = \tau^m + \bar{\tau}^m = 2^m + 1 - #E(GF(2^m))
+        let tau_m = Self::pow(m);
+        let V_m = &Z::from(2) * &tau_m.a0 + &Z::from(Self::MU) * &tau_m.a1;
+        let k_prime = Z(&k.0 >> (a + (m - 9) / 2 - C));
+        // \lambda_i = round((g' + j') / 2^{(m + 5) / 2}) / 2^C
+        let lambda = [&s0, &s1].map(|s| {
+            let g_prime = s * &k_prime;
+            let j_prime = &V_m * &Z(&g_prime.0 >> m);
+            let numerator = Z::from(&R::from(&(g_prime + j_prime, Z::pow2((m + 5) / 2))));
+            R::from(&(numerator, Z::pow2(C)))
+        });
+        let [lambda0, lambda1] = lambda;
+        let q = Self::from(&RTau::new(lambda0, lambda1));
+        // \rho = k - q * \delta
+        let r0 = k - &(&(&s0 + &(&Z::from(Self::MU) * &s1)) * &q.a0) - &Z::from(2) * &(&s1 * &q.a1);
+        let r1 = &s1 * &q.a0 - &s0 * &q.a1;
+        Self::new(r0, r1)
+    }
+
+    // exact division by \tau of an element whose a0 is even,
+    // (a0 + a1 * \tau) / \tau = (a1 + \mu * a0 / 2) - (a0 / 2) * \tau
+    fn div_tau(&self) -> Self {
+        assert!(!self.is_odd(), "Only even elements are divisible by tau!");
+        let a0_half = Z(&self.a0.0 >> 1);
+        Self::new(&self.a1 + &(&Z::from(Self::MU) * &a0_half), -a0_half)
+    }
 }
 
 // Norm of Z[\tau]
-impl Norm<Z> for ZTau {
+impl<K: Koblitz> Norm<Z> for ZTau<K> {
     fn norm(&self) -> Z {
-        let x0 = self.a0 * self.a0 + Self::CHAR * self.a1 * self.a1;
-        let x1 = Self::MU * self.a0 * self.a1;
+        let x0 = &self.a0 * &self.a0 + &Z::from(Self::CHAR) * &(&self.a1 * &self.a1);
+        let x1 = &Z::from(Self::MU) * &(&self.a0 * &self.a1);
         x0 + x1
     }
 }
 
 // refer to "handbook of elliptic and hyperelliptic curve cryptography", Algorithm 15.9
 // find a optimal ZTau which is the closest in terms of lattice distance to RTau
-impl From<&RTau> for ZTau {
-    fn from(rt: &RTau) -> Self {
+impl<K: Koblitz> From<&RTau<K>> for ZTau<K> {
+    fn from(rt: &RTau<K>) -> Self {
         let (f0, f1) = (Z::from(&rt.a0), Z::from(&rt.a1));
-        let (eta0, eta1) = (rt.a0 - R::from(&f0), rt.a1 - R::from(&f1));
+        let (eta0, eta1) = (&rt.a0 - &R::from(&f0), &rt.a1 - &R::from(&f1));
         let (mut h0, mut h1) = (Z::default(), Z::default());
         let (one, two, three, four) = (
-            R::from(&Z(1)),
-            R::from(&Z(2)),
-            R::from(&Z(3)),
-            R::from(&Z(4)),
+            R::from(&Z::from(1)),
+            R::from(&Z::from(2)),
+            R::from(&Z::from(3)),
+            R::from(&Z::from(4)),
         );
-        let r_mu = R::from(&Self::MU);
-        let eta = two * eta0 + eta1 * r_mu;
+        let mu = Z::from(Self::MU);
+        let r_mu = R::from(&mu);
+        let eta = &two * &eta0 + &eta1 * &r_mu;
+        let eta0_minus_3_mu_eta1 = &eta0 - &(&(&three * &r_mu) * &eta1);
+        let eta0_plus_4_mu_eta1 = &eta0 + &(&(&four * &r_mu) * &eta1);
         if eta >= one {
-            if eta0 - three * r_mu * eta1 < -one {
-                h1 = Self::MU;
+            if eta0_minus_3_mu_eta1 < -one.clone() {
+                h1 = mu.clone();
             } else {
-                h0 = Z(1);
-            }
-        } else {
-            if eta0 + four * r_mu * eta1 >= two {
-                h1 = Self::MU;
+                h0 = Z::from(1);
             }
+        } else if eta0_plus_4_mu_eta1 >= two {
+            h1 = mu.clone();
         }
-        if eta < -one {
-            if eta0 - three * r_mu * eta1 >= one {
-                h1 = -Self::MU;
+        if eta < -one.clone() {
+            if eta0_minus_3_mu_eta1 >= one {
+                h1 = -mu;
             } else {
-                h0 = -Z(1);
-            }
-        } else {
-            if eta0 + four * r_mu * eta1 < -two {
-                h1 = -Self::MU;
+                h0 = Z::from(-1);
             }
+        } else if eta0_plus_4_mu_eta1 < -two {
+            h1 = -mu;
         }
-        let (a0, a1) = (f0 + h0, f1 + h1);
-        ZTau { a0, a1 }
+        Self::new(f0 + h0, f1 + h1)
     }
 }
 
-impl From<&LucasSequence> for ZTau {
-    fn from(s: &LucasSequence) -> Self {
-        Self::new(-Self::CHAR * s.u0, s.u1)
+impl<K: Koblitz> From<&LucasSequence<K>> for ZTau<K> {
+    fn from(s: &LucasSequence<K>) -> Self {
+        Self::new(-(&Z::from(Self::CHAR) * &s.u0), s.u1.clone())
     }
 }
 
-impl Tau for ZTau {
+impl<K: Koblitz> Tau for ZTau<K> {
+    type Curve = K;
     fn one() -> Self {
-        Self { a0: Z(1), a1: Z(0) }
+        Self::new(Z::from(1), Z::from(0))
     }
     fn zero() -> Self {
-        Self { a0: Z(0), a1: Z(0) }
+        Self::new(Z::from(0), Z::from(0))
     }
     // Z[\bar{\tau}] -> Z[\tau]
     fn automorphism(&self) -> Self {
-        Self {
-            a0: self.a0 + Self::MU * self.a1,
-            a1: -self.a1,
-        }
-    }
-    // Z[\tau] -> Z[2^w]
-    fn isomorphism(&self, h_w: Z) -> Z {
-        self.a0 + self.a1 * h_w
+        Self::new(
+            &self.a0 + &(&Z::from(Self::MU) * &self.a1),
+            -self.a1.clone(),
+        )
     }
 }
 
-impl Add<Self> for ZTau {
+impl<K: Koblitz> Add<Self> for ZTau<K> {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
-        Self {
-            a0: self.a0 + rhs.a0,
-            a1: self.a1 + rhs.a1,
-        }
+        &self + &rhs
+    }
+}
+
+impl<'a, K: Koblitz> Add<&'a ZTau<K>> for &'a ZTau<K> {
+    type Output = ZTau<K>;
+    fn add(self, rhs: &'a ZTau<K>) -> Self::Output {
+        ZTau::new(&self.a0 + &rhs.a0, &self.a1 + &rhs.a1)
     }
 }
 
-impl Sub<Self> for ZTau {
+impl<K: Koblitz> Sub<Self> for ZTau<K> {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
-        Self {
-            a0: self.a0 - rhs.a0,
-            a1: self.a1 - rhs.a1,
-        }
+        &self - &rhs
+    }
+}
+
+impl<'a, K: Koblitz> Sub<&'a ZTau<K>> for &'a ZTau<K> {
+    type Output = ZTau<K>;
+    fn sub(self, rhs: &'a ZTau<K>) -> Self::Output {
+        ZTau::new(&self.a0 - &rhs.a0, &self.a1 - &rhs.a1)
     }
 }
 
 // (a + b * \tau) * (c + d * \tau)
-impl Mul<Self> for ZTau {
+impl<K: Koblitz> Mul<Self> for ZTau<K> {
     type Output = Self;
     fn mul(self, rht: Self) -> Self::Output {
-        let a0 = self.a0 * rht.a0 - Self::CHAR * self.a1 * rht.a1;
-        let a1 = self.a0 * rht.a1 + self.a1 * rht.a0 + Self::MU * self.a1 * rht.a1;
-        Self { a0, a1 }
+        &self * &rht
+    }
+}
+
+impl<'a, K: Koblitz> Mul<&'a ZTau<K>> for &'a ZTau<K> {
+    type Output = ZTau<K>;
+    fn mul(self, rht: &'a ZTau<K>) -> Self::Output {
+        let a1_mul_b1 = &self.a1 * &rht.a1;
+        let a0 = &self.a0 * &rht.a0 - &Z::from(ZTau::<K>::CHAR) * &a1_mul_b1;
+        let a1 = &self.a0 * &rht.a1 + &self.a1 * &rht.a0 + &Z::from(K::MU) * &a1_mul_b1;
+        ZTau::new(a0, a1)
     }
 }
 
 // (a + b * \tau) / N = a / N + b / N * \tau
-impl Div<Z> for ZTau {
-    type Output = RTau;
+impl<K: Koblitz> Div<Z> for ZTau<K> {
+    type Output = RTau<K>;
     fn div(self, rht: Z) -> Self::Output {
-        RTau::new(R::from(&(self.a0, rht)), R::from(&(self.a1, rht)))
+        RTau::new(R::from(&(self.a0, rht.clone())), R::from(&(self.a1, rht)))
     }
 }
 
 // refer to "Handbook of Elliptic and Hyperelliptic Curve Cryptography", Algorithm 15.11
 // (a + b * \tau) / (c + d * \tau)
 // = (a + b * \tau) * (c + d * \bar{tau}) / N(c + d * \tau)
-impl Div<Self> for ZTau {
-    type Output = (Self, Self);
-    fn div(self, rht: Self) -> Self::Output {
+impl<'a, K: Koblitz> Div<&'a ZTau<K>> for &'a ZTau<K> {
+    type Output = (ZTau<K>, ZTau<K>);
+    fn div(self, rht: &'a ZTau<K>) -> Self::Output {
         let N = rht.norm();
-        let g = self * rht.conjugate();
+        let g = self * &rht.conjugate();
         let g_div_N = g / N;
         let k = ZTau::from(&g_div_N);
-        let ro = self - k * rht;
+        let ro = self - &(&k * rht);
         (k, ro)
     }
 }
 
-impl Neg for ZTau {
+impl<K: Koblitz> Div<Self> for ZTau<K> {
+    type Output = (Self, Self);
+    fn div(self, rht: Self) -> Self::Output {
+        &self / &rht
+    }
+}
+
+impl<K: Koblitz> Neg for ZTau<K> {
     type Output = Self;
     fn neg(self) -> Self::Output {
-        Self {
-            a0: -self.a0,
-            a1: -self.a1,
-        }
+        Self::new(-self.a0, -self.a1)
     }
 }
 //////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+// little-endian digits of a \tau-adic expansion, i.e. \sum_i d_i * \tau^i
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ZTauExpansion(Vec<Z>);
 
 impl ZTauExpansion {
-    fn len(&self) -> usize {
+    pub fn new(digits: Vec<Z>) -> Self {
+        Self(digits)
+    }
+
+    pub fn digits(&self) -> &[Z] {
+        &self.0
+    }
+
+    pub fn len(&self) -> usize {
         self.0.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
 }
 
 impl Index<usize> for ZTauExpansion {
@@ -387,31 +507,47 @@ impl Neg for ZTauExpansion {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::binary_curve::{k163::K163, k233::K233};
     use rand::{self, Rng};
 
+    // K-233 has a_2 = 0, i.e. \mu = -1, and K-163 has a_2 = 1, i.e. \mu = 1
+    type ZTauMinus = ZTau<K233>;
+    type ZTauPlus = ZTau<K163>;
+
     #[test]
     fn test_r_to_z() {
-        let r1 = R(Rational64::new(1, 2));
-        let r2 = R(Rational64::new(-1, 2));
+        let r1 = R(BigRational::new(1.into(), 2.into()));
+        let r2 = R(BigRational::new((-1).into(), 2.into()));
         assert!(
-            (Z::from(&r1) == Z(0)) && (Z::from(&r2) == Z(0)),
+            (Z::from(&r1) == Z::from(0)) && (Z::from(&r2) == Z::from(0)),
             "Test for round(1 / 2) == round(-1 / 2) == 0 failed!"
         );
     }
 
     #[test]
     fn test_rtau_to_ztau() {
-        let u = RTau::new(R(Rational64::new(8, 5)), R(Rational64::new(12, 5)));
-        let u_expected = ZTau::new(Z(1), Z(2));
+        let u = RTau::<K233>::new(
+            R(BigRational::new(8.into(), 5.into())),
+            R(BigRational::new(12.into(), 5.into())),
+        );
+        let u_expected = ZTau::new(Z::from(1), Z::from(2));
         assert_eq!(ZTau::from(&u), u_expected, "Test for From(Rtau) failed!");
     }
 
     #[test]
     fn test_ztau_mul() {
+        // (1 + 2 * \tau) * (2 + 3 * \tau) = -10 + (7 + 6 * \mu) * \tau
         let (u, v, w_expected) = (
-            ZTau::new(Z(1), Z(2)),
-            ZTau::new(Z(2), Z(3)),
-            ZTau::new(Z(-10), Z(1)),
+            ZTauMinus::new(Z::from(1), Z::from(2)),
+            ZTauMinus::new(Z::from(2), Z::from(3)),
+            ZTauMinus::new(Z::from(-10), Z::from(1)),
+        );
+        let w = u * v;
+        assert_eq!(w, w_expected, "Test for multiplication of ZTau failed!");
+        let (u, v, w_expected) = (
+            ZTauPlus::new(Z::from(1), Z::from(2)),
+            ZTauPlus::new(Z::from(2), Z::from(3)),
+            ZTauPlus::new(Z::from(-10), Z::from(13)),
         );
         let w = u * v;
         assert_eq!(w, w_expected, "Test for multiplication of ZTau failed!");
@@ -421,17 +557,17 @@ mod tests {
     fn test_ztau_div() {
         let mut rng = rand::thread_rng();
         for _ in 0..1000 {
-            let u = ZTau::new(
-                Z(rng.gen_range(0..10000000i64)),
-                Z(rng.gen_range(0..10000000i64)),
+            let u = ZTauMinus::new(
+                Z::from(rng.gen_range(0..10000000i64)),
+                Z::from(rng.gen_range(0..10000000i64)),
             );
-            let v = ZTau::new(
-                Z(rng.gen_range(0..10000000i64)),
-                Z(rng.gen_range(0..10000000i64)),
+            let v = ZTauMinus::new(
+                Z::from(rng.gen_range(0..10000000i64)),
+                Z::from(rng.gen_range(0..10000000i64)),
             );
-            let (_, ro) = u / v;
+            let (_, ro) = &u / &v;
             assert!(
-                Z(7) * ro.norm() < Z(4) * v.norm(),
+                Z::from(7) * ro.norm() < Z::from(4) * v.norm(),
                 "Test for division of ZTau failed!"
             );
         }
@@ -439,7 +575,14 @@ mod tests {
 
     #[test]
     fn test_tau_naf() {
-        let u = ZTau::new(Z(409), Z(0));
+        let u = ZTauMinus::new(Z::from(409), Z::from(0));
+        let w = u.tauNAF();
+        assert_eq!(
+            ZTau::tauNAF_reverse(&w),
+            u,
+            "Reconfirmation of the tauNAF result failed!"
+        );
+        let u = ZTauPlus::new(Z::from(409), Z::from(0));
         let w = u.tauNAF();
         assert_eq!(
             ZTau::tauNAF_reverse(&w),
@@ -450,51 +593,173 @@ mod tests {
 
     #[test]
     fn test_tau_pow() {
-        let tau_powers = vec![
-            ZTau::one(),
-            ZTau::default(),
-            ZTau::new(Z(-2), Z(-1)),
-            ZTau::new(Z(2), Z(-1)),
-            ZTau::new(Z(2), Z(3)),
+        let tau_powers = [
+            ZTauMinus::one(),
+            ZTauMinus::default(),
+            ZTauMinus::new(Z::from(-2), Z::from(-1)),
+            ZTauMinus::new(Z::from(2), Z::from(-1)),
+            ZTauMinus::new(Z::from(2), Z::from(3)),
         ];
-        for w in 0..tau_powers.len() {
-            assert_eq!(ZTau::pow(w), tau_powers[w]);
+        for (w, tau_power) in tau_powers.iter().enumerate() {
+            assert_eq!(ZTauMinus::pow(w), *tau_power);
+        }
+        let tau_powers = [
+            ZTauPlus::one(),
+            ZTauPlus::default(),
+            ZTauPlus::new(Z::from(-2), Z::from(1)),
+            ZTauPlus::new(Z::from(-2), Z::from(-1)),
+            ZTauPlus::new(Z::from(2), Z::from(-3)),
+        ];
+        for (w, tau_power) in tau_powers.iter().enumerate() {
+            assert_eq!(ZTauPlus::pow(w), *tau_power);
         }
     }
 
+    // n = 409 (mod \delta), \delta = (\tau^d - 1) / (\tau - 1), and its tauNAF_w expansion
+    fn tau_naf_w<K: Koblitz>(w: usize, d: usize) -> (ZTau<K>, Vec<ZTauExpansion>) {
+        let u = ZTau::<K>::new(Z::from(409), Z::from(0));
+        let delta = ZTau::delta(d);
+        let n = u.reduce(&delta);
+
+        // test h_w is in the kernel of map \phi_w: Z[\tau] -> Z[2^w]
+        let h_w = ZTau::<K>::h_w(w);
+        let tau_w = ZTau::<K>::pow(w);
+        assert_eq!(tau_w.isomorphism(&h_w) % Z::pow2(w), Z::from(0));
+
+        // \sum_i \alpha_{u_i} * \tau^i = n
+        let n_naf_w = n.tauNAFw(w);
+        let mut v = ZTau::zero();
+        for (i, alpha) in n_naf_w.iter().enumerate().rev() {
+            if !alpha.is_empty() {
+                v = v + ZTau::tauNAF_reverse(alpha) * ZTau::pow(i);
+            }
+        }
+        assert_eq!(v, n, "Reconfirmation of the tauNAF_w result failed!");
+        (n, n_naf_w)
+    }
+
     #[test]
     fn test_tau_naf_w() {
-        let w = 4;
-        let d = 11;
-        let u = ZTau::new(Z(409), Z(0));
+        let (w, d) = (4, 11);
+        let (_, n_naf_w) = tau_naf_w::<K163>(w, d);
+        assert_eq!(
+            n_naf_w,
+            vec![
+                ZTauExpansion(vec![Z::from(-1), Z::from(0), Z::from(0), Z::from(-1)]),
+                ZTauExpansion(vec![]),
+                ZTauExpansion(vec![]),
+                ZTauExpansion(vec![]),
+                ZTauExpansion(vec![Z::from(1), Z::from(0), Z::from(0), Z::from(1)]),
+                ZTauExpansion(vec![]),
+                ZTauExpansion(vec![]),
+                ZTauExpansion(vec![]),
+                ZTauExpansion(vec![Z::from(1), Z::from(0), Z::from(1)])
+            ],
+        );
+        let (_, n_naf_w) = tau_naf_w::<K233>(w, d);
+        assert!(!n_naf_w.is_empty());
+    }
 
-        // delta = (\tau^d - 1) / (\tau - 1)
-        let delta = ZTau::delta(d);
-        let n = u.reduce(delta);
+    #[test]
+    fn test_tau_naf_big_scalar() {
+        // a 233-bit scalar which overflows any machine word in its tau-adic expansion
+        let k = Z(BigInt::parse_bytes(
+            b"17c14c59e6253fa1903f05141fd556d02d1aec2c77b038098981ecf8166",
+            16,
+        )
+        .unwrap());
+        let u = ZTauMinus::new(k, Z::from(0));
+        let w = u.tauNAF();
+        assert!(
+            w.len() > 2 * 233 - 8,
+            "tauNAF of an unreduced scalar has about 2m digits"
+        );
+        assert_eq!(
+            ZTau::tauNAF_reverse(&w),
+            u,
+            "Reconfirmation of the tauNAF result of a big scalar failed!"
+        );
+        let w4 = u.tauNAFw(4);
+        let mut v = ZTau::zero();
+        for (i, alpha) in w4.iter().enumerate().rev() {
+            if !alpha.is_empty() {
+                v = v + ZTau::tauNAF_reverse(alpha) * ZTau::pow(i);
+            }
+        }
+        assert_eq!(
+            v, u,
+            "Reconfirmation of the tauNAF_w result of a big scalar failed!"
+        );
+    }
 
-        // test h_w is in the kernel of map \phi_w: Z[\tau] -> Z[2^w]
-        let h_w = ZTau::h_w(w);
-        let tau_w = ZTau::pow(w);
-        assert_eq!(tau_w.isomorphism(h_w) % Z(1 << w), Z(0));
-
-        if ZTau::MU == Z(1) {
-            let n_naf_w = n.tauNAFw(w);
-            assert_eq!(
-                n_naf_w,
-                vec![
-                    ZTauExpansion(vec![Z(-1), Z(0), Z(0), Z(-1)]),
-                    ZTauExpansion(vec![]),
-                    ZTauExpansion(vec![]),
-                    ZTauExpansion(vec![]),
-                    ZTauExpansion(vec![Z(1), Z(0), Z(0), Z(1)]),
-                    ZTauExpansion(vec![]),
-                    ZTauExpansion(vec![]),
-                    ZTauExpansion(vec![]),
-                    ZTauExpansion(vec![Z(1), Z(0), Z(1)])
-                ],
+    #[test]
+    fn test_tau_pow_big() {
+        // \tau^m - 1 has norm #E(GF(2^m)) = 4 * n for K-233
+        let tau_m = ZTauMinus::pow(233);
+        assert_eq!(tau_m, ZTau::default().exp(233));
+        let n = Z(BigInt::parse_bytes(
+            b"8000000000000000000000000000069d5bb915bcd46efb1ad5f173abdf",
+            16,
+        )
+        .unwrap());
+        assert_eq!((tau_m - ZTau::one()).norm(), Z::from(4) * n.clone());
+        assert_eq!(ZTauMinus::delta(233).norm(), n);
+
+        // \tau^m - 1 has norm #E(GF(2^m)) = 2 * n for K-163
+        let tau_m = ZTauPlus::pow(163);
+        assert_eq!(tau_m, ZTau::default().exp(163));
+        let n = Z(BigInt::parse_bytes(b"4000000000000000000020108a2e0cc0d99f8a5ef", 16).unwrap());
+        assert_eq!((tau_m - ZTau::one()).norm(), Z::from(2) * n.clone());
+        assert_eq!(ZTauPlus::delta(163).norm(), n);
+    }
+
+    #[test]
+    fn test_inv_mod() {
+        let modulus = Z::pow2(100);
+        let u = Z::from(-12345);
+        let u_inv = u.inv_mod(&modulus);
+        assert_eq!((u * u_inv).reduce(&modulus), Z::from(1));
+        assert_eq!(
+            Z::from(-1234).try_inv_mod(&modulus),
+            Err(Error::NotInvertible)
+        );
+        assert_eq!(
+            Z::from(3).try_inv_mod(&Z::from(0)),
+            Err(Error::NotInvertible)
+        );
+    }
+
+    fn partial_mod<K: Koblitz>(m: usize) {
+        let n = ZTau::<K>::delta(m).norm();
+        let delta = ZTau::<K>::delta(m);
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            let bytes = (0..m.div_ceil(8))
+                .map(|_| rng.r#gen::<u8>())
+                .collect::<Vec<_>>();
+            let k = Z(BigInt::from_bytes_be(num_bigint::Sign::Plus, &bytes)).reduce(&n);
+            let rho = ZTau::<K>::partial_mod(&k, m);
+            // \rho = k (mod \delta)
+            let (_, remainder) = &(ZTau::new(k.clone(), Z::from(0)) - rho.clone()) / &delta;
+            assert!(
+                remainder.is_zero(),
+                "Test for congruence of partial reduction failed!"
+            );
+            // N(\rho) is close to n, and tauNAF(\rho) has at most m + a + 3 digits
+            assert!(
+                rho.norm() < Z::from(2) * n.clone(),
+                "Test for norm of partial reduction failed!"
+            );
+            assert!(
+                rho.tauNAF().len() <= m + ((1 + K::MU) / 2) as usize + 3,
+                "Test for length of partial reduction failed!"
             );
-        } else {
-            assert!(true, "No test case here!")
         }
     }
+
+    #[test]
+    fn test_partial_mod() {
+        partial_mod::<K233>(233);
+        partial_mod::<K163>(163);
+    }
 }
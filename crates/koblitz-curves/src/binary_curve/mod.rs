@@ -1,4 +1,16 @@
 #![allow(dead_code, non_snake_case)]
+pub mod b163;
+pub mod b233;
+pub mod b283;
+pub mod b409;
+pub mod b571;
 pub mod curve;
+pub mod k163;
 pub mod k233;
+pub mod k283;
+pub mod k409;
+pub mod k571;
+pub mod koblitz;
 pub mod koblitz_tau;
+pub mod params;
+pub mod sec1;
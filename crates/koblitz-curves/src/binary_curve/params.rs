@@ -0,0 +1,532 @@
+/// Binary curve parameters given as hex strings, validated by a builder and turned into curve types by a macro
+use crate::binary_field::BinaryField;
+use crate::binary_field::polynomial::{BinaryPolynomial, WORD, WORD_SIZE};
+use crate::error::Error;
+use derive_builder::{Builder, UninitializedFieldError};
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+// parameters of E(\bar{K}): y^2 + xy = x^3 + a_2 x^2 + a_6 over the binary field Field,
+// with a generator G of prime order n and the cofactor h = #E / n
+#[derive(Builder, Clone, Debug, PartialEq, Eq)]
+#[builder(build_fn(private, name = "build_unchecked", error = "Error"))]
+pub struct CurveParameters<const N: usize, Field: BinaryField<N>> {
+    // SEC 2 name, e.g. sect233k1
+    #[builder(setter(into))]
+    pub name: String,
+    // object identifier, e.g. 1.3.132.0.26
+    #[builder(setter(into))]
+    pub oid: String,
+    #[builder(setter(into), field(ty = "String", build = "parse_field(&self.a2)?"))]
+    pub a2: Field,
+    #[builder(setter(into), field(ty = "String", build = "parse_field(&self.a6)?"))]
+    pub a6: Field,
+    #[builder(
+        setter(into),
+        field(ty = "String", build = "parse_field(&self.generator_x)?")
+    )]
+    pub generator_x: Field,
+    #[builder(
+        setter(into),
+        field(ty = "String", build = "parse_field(&self.generator_y)?")
+    )]
+    pub generator_y: Field,
+    #[builder(
+        setter(into),
+        field(ty = "String", build = "parse_order(&self.order)?")
+    )]
+    pub order: BigUint,
+    pub cofactor: u64,
+    // \sqrt(a_6), which is computed rather than given
+    #[builder(setter(skip), default = "Field::zero()")]
+    pub a6_sqrt: Field,
+}
+
+impl From<UninitializedFieldError> for Error {
+    fn from(_: UninitializedFieldError) -> Self {
+        Error::MissingParameter
+    }
+}
+
+impl<const N: usize, Field: BinaryField<N>> CurveParametersBuilder<N, Field> {
+    // parse the parameters, compute \sqrt(a_6), and check that the curve is non-singular, that the generator is on
+    // the curve and that n * G = O
+    pub fn build(&self) -> Result<CurveParameters<N, Field>, Error> {
+        let mut params = self.build_unchecked()?;
+        if params.a6.is_zero() {
+            return Err(Error::InvalidCurveParameters);
+        }
+        params.a6_sqrt = params.a6.sqrt();
+        let g = (params.generator_x, params.generator_y);
+        if !params.is_on_curve(&g) {
+            return Err(Error::NotOnCurve);
+        }
+        // n is an odd prime and h * n is the number of points, so G generates the subgroup of order n once n * G = O
+        if !is_probable_prime(&params.order)
+            || !in_hasse_interval(&(&params.order * params.cofactor), Field::M)
+            || params.scalar_mul(&g, &params.order).is_some()
+        {
+            return Err(Error::InvalidCurveParameters);
+        }
+        Ok(params)
+    }
+}
+
+impl<const N: usize, Field: BinaryField<N>> CurveParameters<N, Field> {
+    // y^2 + xy = x^3 + a_2 x^2 + a_6 for an affine point
+    pub fn is_on_curve(&self, p: &(Field, Field)) -> bool {
+        let (x, y) = *p;
+        let x2 = x.squaring();
+        y.squaring() + x * y == x2 * x + self.a2 * x2 + self.a6
+    }
+
+    // affine addition, where None is the point at infinity, Algorithm 3.2 in "Guide to Elliptic Curve Cryptography"
+    fn add(&self, p: Option<(Field, Field)>, q: Option<(Field, Field)>) -> Option<(Field, Field)> {
+        let ((x1, y1), (x2, y2)) = match (p, q) {
+            (None, _) => return q,
+            (_, None) => return p,
+            (Some(p), Some(q)) => (p, q),
+        };
+        if x1 == x2 {
+            // -P = (x, x + y), and 2P = O when x = 0
+            if y1 != y2 || x1.is_zero() {
+                return None;
+            }
+            let lambda = x1 + y1 / x1;
+            let x3 = lambda.squaring() + lambda + self.a2;
+            return Some((x3, x1.squaring() + (lambda + Field::one()) * x3));
+        }
+        let lambda = (y1 + y2) / (x1 + x2);
+        let x3 = lambda.squaring() + lambda + x1 + x2 + self.a2;
+        Some((x3, lambda * (x1 + x3) + x3 + y1))
+    }
+
+    // k * P with left-to-right double and add in affine coordinates
+    fn scalar_mul(&self, p: &(Field, Field), k: &BigUint) -> Option<(Field, Field)> {
+        let mut result = None;
+        for i in (0..k.bits()).rev() {
+            result = self.add(result, result);
+            if k.bit(i) {
+                result = self.add(result, Some(*p));
+            }
+        }
+        result
+    }
+}
+
+fn parse_field<const N: usize, Field: BinaryField<N>>(s: &str) -> Result<Field, Error> {
+    let hex_string = s.strip_prefix("0x").ok_or(Error::InvalidHexString)?;
+    let bytes = BigUint::parse_bytes(hex_string.as_bytes(), 16)
+        .ok_or(Error::InvalidHexString)?
+        .to_bytes_be();
+    let l = Field::M.div_ceil(8);
+    if bytes.len() > l {
+        return Err(Error::DegreeTooLarge);
+    }
+    let mut padded = vec![0u8; l - bytes.len()];
+    padded.extend(bytes);
+    Field::from_be_bytes(&padded)
+}
+
+fn parse_order(s: &str) -> Result<BigUint, Error> {
+    let hex_string = s.strip_prefix("0x").ok_or(Error::InvalidHexString)?;
+    BigUint::parse_bytes(hex_string.as_bytes(), 16).ok_or(Error::InvalidHexString)
+}
+
+// |#E - (2^m + 1)| <= 2 * \sqrt(2^m), i.e. (#E - 2^m - 1)^2 <= 2^{m + 2} by Hasse's theorem
+fn in_hasse_interval(order: &BigUint, m: usize) -> bool {
+    let q = (BigUint::one() << m) + 1u32;
+    let d = if order > &q { order - &q } else { &q - order };
+    &d * &d <= BigUint::one() << (m + 2)
+}
+
+// Miller-Rabin with the first primes as bases, n = 2^s * d + 1
+fn is_probable_prime(n: &BigUint) -> bool {
+    const BASES: [u32; 16] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+    if n < &BigUint::from(2u32) {
+        return false;
+    }
+    if let Some(p) = BASES.iter().find(|p| n % **p == BigUint::zero()) {
+        return n == &BigUint::from(*p);
+    }
+    let n_minus_one = n - 1u32;
+    let s = n_minus_one
+        .trailing_zeros()
+        .expect("n - 1 is even and nonzero");
+    let d = &n_minus_one >> s;
+    BASES.iter().all(|a| {
+        let mut x = BigUint::from(*a).modpow(&d, n);
+        if x.is_one() || x == n_minus_one {
+            return true;
+        }
+        for _ in 1..s {
+            x = x.modpow(&BigUint::from(2u32), n);
+            if x == n_minus_one {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+// the checks of CurveParametersBuilder that are cheap enough for const evaluation, run by define_binary_curve! on
+// every build: a_6 != 0, the field elements have degree < m, G is on the curve, n is odd and h * n is within
+// 2^m + 1 +- 2^{\lceil m / 2 \rceil + 1}, which is slightly wider than the Hasse interval, n * G = O is left to the builder
+#[allow(clippy::too_many_arguments)]
+pub const fn check_curve_constants<const N: usize>(
+    m: usize,
+    R: &[usize],
+    a2: &BinaryPolynomial<N>,
+    a6: &BinaryPolynomial<N>,
+    gx: &BinaryPolynomial<N>,
+    gy: &BinaryPolynomial<N>,
+    order: &BinaryPolynomial<N>,
+    cofactor: u64,
+) -> Result<(), &'static str> {
+    if !(below(a2, m) && below(a6, m) && below(gx, m) && below(gy, m)) {
+        return Err("Curve constant is out of the binary field!");
+    }
+    if is_zero(a6) {
+        return Err("a_6 of a binary curve should be nonzero!");
+    }
+    // y^2 + xy = x^3 + a_2 x^2 + a_6
+    let x2 = gx.square_sparse(m, R);
+    let lhs = xor(&gy.square_sparse(m, R), &gx.mul_sparse(gy, m, R));
+    let rhs = xor(
+        &xor(&x2.mul_sparse(gx, m, R), &a2.mul_sparse(&x2, m, R)),
+        a6,
+    );
+    if !is_zero(&xor(&lhs, &rhs)) {
+        return Err("Generator is not on the curve!");
+    }
+    if order.0[0] & 1 == 0 {
+        return Err("Order of the generator should be an odd prime!");
+    }
+    // the words of n and h * n as little ending integers
+    let mut t = [0 as WORD; N];
+    let mut carry = 0u128;
+    let mut i = 0;
+    while i < N {
+        let v = order.0[i] as u128 * cofactor as u128 + carry;
+        t[i] = v as WORD;
+        carry = v >> WORD_SIZE;
+        i += 1;
+    }
+    assert!(
+        m < N * WORD_SIZE,
+        "2^m should fit in the words of the field!"
+    );
+    let (mut q, mut bound) = ([0 as WORD; N], [0 as WORD; N]);
+    q[0] = 1;
+    q[m / WORD_SIZE] |= 1 << (m % WORD_SIZE);
+    let e = m.div_ceil(2) + 1;
+    bound[e / WORD_SIZE] = 1 << (e % WORD_SIZE);
+    let d = if ge(&t, &q) { sub(&t, &q) } else { sub(&q, &t) };
+    if carry != 0 || !ge(&bound, &d) {
+        return Err("Cofactor times order is out of the Hasse interval!");
+    }
+    Ok(())
+}
+
+const fn is_zero<const N: usize>(a: &BinaryPolynomial<N>) -> bool {
+    let mut i = 0;
+    while i < N {
+        if a.0[i] != 0 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+// deg(a) < m
+const fn below<const N: usize>(a: &BinaryPolynomial<N>, m: usize) -> bool {
+    let mut i = m;
+    while i < N * WORD_SIZE {
+        if (a.0[i / WORD_SIZE] >> (i % WORD_SIZE)) & 1 == 1 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn xor<const N: usize>(
+    a: &BinaryPolynomial<N>,
+    b: &BinaryPolynomial<N>,
+) -> BinaryPolynomial<N> {
+    let mut c = a.0;
+    let mut i = 0;
+    while i < N {
+        c[i] ^= b.0[i];
+        i += 1;
+    }
+    BinaryPolynomial(c)
+}
+
+// a >= b as integers
+const fn ge<const N: usize>(a: &[WORD; N], b: &[WORD; N]) -> bool {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+    true
+}
+
+// a - b as integers, where a >= b
+const fn sub<const N: usize>(a: &[WORD; N], b: &[WORD; N]) -> [WORD; N] {
+    let mut c = [0 as WORD; N];
+    let mut borrow = false;
+    let mut i = 0;
+    while i < N {
+        let (v, b1) = a[i].overflowing_sub(b[i]);
+        let (v, b2) = v.overflowing_sub(borrow as WORD);
+        c[i] = v;
+        borrow = b1 || b2;
+        i += 1;
+    }
+    c
+}
+
+/// Define a binary curve type from hex parameters over a field module generated by `define_binary_field!`,
+/// whose constants are parsed at compile time with \sqrt(a_6) computed by squarings, and whose generated tests check
+/// them against CurveParametersBuilder, the order of the scalar field included. The field module is named as it is
+/// in scope at the invocation, so that curves can be defined outside this crate too:
+///
+/// ```
+/// use koblitz_curves::{BinaryCurve, Fn233, K233};
+///
+/// mod gf233 {
+///     use koblitz_curves::BinaryField;
+///     koblitz_curves::define_binary_field!(Gf233, 233, [74]);
+/// }
+///
+/// koblitz_curves::define_binary_curve!(
+///     Sect233k1, gf233::Gf233, Fn233,
+///     name: "sect233k1",
+///     oid: "1.3.132.0.26",
+///     a2: "0x0",
+///     a6: "0x1",
+///     generator_x: "0x017232ba853a7e731af129f22ff4149563a419c26bf50a4c9d6eefad6126",
+///     generator_y: "0x01db537dece819b7f70f555a67c427a8cd9bf18aeb9b56e0c11056fae6a3",
+///     order: "0x8000000000000000000000000000069d5bb915bcd46efb1ad5f173abdf",
+///     cofactor: 4,
+/// );
+///
+/// let k = Fn233::from(0x1234u64);
+/// let (x, y) = (Sect233k1::GENERATOR * k).to_affine().unwrap();
+/// let (x1, y1) = (K233::GENERATOR * k).to_affine().unwrap();
+/// assert_eq!((x.to_hex_string(), y.to_hex_string()), (x1.to_hex_string(), y1.to_hex_string()));
+/// ```
+#[macro_export]
+macro_rules! define_binary_curve {
+    (
+        $Curve:ident, $field:ident::$Field:ident, $Scalar:ty,
+        name: $name:literal,
+        oid: $oid:literal,
+        a2: $a2:literal,
+        a6: $a6:literal,
+        generator_x: $gx:literal,
+        generator_y: $gy:literal,
+        order: $order:literal,
+        cofactor: $h:literal $(,)?
+    ) => {
+        #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+        pub struct $Curve;
+
+        impl $Curve {
+            pub const NAME: &'static str = $name;
+            pub const OID: &'static str = $oid;
+        }
+
+        const _: () = {
+            use $crate::binary_curve::curve::{BinaryCurve, ProjectivePoint};
+            use $crate::binary_curve::params::check_curve_constants;
+            use $crate::binary_field::polynomial::BinaryPolynomial;
+            use $field::{M, N, R, $Field};
+
+            const RAW_A2: BinaryPolynomial<N> = BinaryPolynomial::from_hex_str($a2);
+            const RAW_A6: BinaryPolynomial<N> = BinaryPolynomial::from_hex_str($a6);
+            const RAW_GX: BinaryPolynomial<N> = BinaryPolynomial::from_hex_str($gx);
+            const RAW_GY: BinaryPolynomial<N> = BinaryPolynomial::from_hex_str($gy);
+            const RAW_ORDER: BinaryPolynomial<N> = BinaryPolynomial::from_hex_str($order);
+            // a wrong constant fails the build rather than only the generated test
+            if let Err(e) =
+                check_curve_constants(M, R, &RAW_A2, &RAW_A6, &RAW_GX, &RAW_GY, &RAW_ORDER, $h)
+            {
+                panic!("{}", e);
+            }
+
+            impl BinaryCurve<N, $Field> for $Curve {
+                type Scalar = $Scalar;
+                const COFACTOR: u64 = $h;
+                const A2: $Field = $Field(RAW_A2);
+                const A6: $Field = $Field(RAW_A6);
+                const IDENTITY: ProjectivePoint<N, $Field, Self> = ProjectivePoint {
+                    x: $Field(BinaryPolynomial::from_hex_str("0x1")),
+                    y: $Field(BinaryPolynomial::from_hex_str("0x0")),
+                    z: $Field(BinaryPolynomial::from_hex_str("0x0")),
+                    marker: std::marker::PhantomData,
+                };
+                const GENERATOR: ProjectivePoint<N, $Field, Self> = ProjectivePoint {
+                    x: $Field(RAW_GX),
+                    y: $Field(RAW_GY),
+                    z: $Field(BinaryPolynomial::from_hex_str("0x1")),
+                    marker: std::marker::PhantomData,
+                };
+                const A6_SQRT: $Field = $Field(RAW_A6.sqrt_sparse(M, R));
+            }
+        };
+
+        #[cfg(test)]
+        mod curve_tests {
+            use super::$field::{N, $Field};
+            use super::*;
+            use $crate::binary_curve::curve::BinaryCurve;
+            use $crate::binary_curve::params::CurveParametersBuilder;
+            use $crate::scalar_field::ScalarField;
+
+            #[test]
+            fn test_curve_parameters() {
+                let params = CurveParametersBuilder::<N, $Field>::default()
+                    .name($name)
+                    .oid($oid)
+                    .a2($a2)
+                    .a6($a6)
+                    .generator_x($gx)
+                    .generator_y($gy)
+                    .order($order)
+                    .cofactor($h)
+                    .build()
+                    .expect("Invalid curve parameters!");
+                let g = <$Curve>::GENERATOR;
+                assert_eq!((params.a2, params.a6), (<$Curve>::A2, <$Curve>::A6));
+                assert_eq!((params.generator_x, params.generator_y), (g.x, g.y));
+                assert_eq!(params.a6_sqrt, <$Curve>::A6_SQRT);
+                assert_eq!(params.order, <$Scalar>::modulus());
+            }
+        }
+    };
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary_field::fq233::{Fq233, M, N, R};
+
+    fn sect233k1() -> CurveParametersBuilder<N, Fq233> {
+        let mut builder = CurveParametersBuilder::default();
+        builder
+            .name("sect233k1")
+            .oid("1.3.132.0.26")
+            .a2("0x0")
+            .a6("0x1")
+            .generator_x("0x017232ba853a7e731af129f22ff4149563a419c26bf50a4c9d6eefad6126")
+            .generator_y("0x01db537dece819b7f70f555a67c427a8cd9bf18aeb9b56e0c11056fae6a3")
+            .order("0x8000000000000000000000000000069d5bb915bcd46efb1ad5f173abdf")
+            .cofactor(4);
+        builder
+    }
+
+    #[test]
+    fn test_builder() {
+        let params = sect233k1().build().unwrap();
+        assert_eq!(params.a6_sqrt, Fq233::one());
+        assert_eq!(params.name, "sect233k1");
+        // n + 2 is not the order of G, and a flipped bit moves G off the curve
+        let mut order = params.order.clone() + 2u32;
+        assert_eq!(
+            sect233k1().order(format!("0x{:x}", order)).build(),
+            Err(Error::InvalidCurveParameters)
+        );
+        order -= 2u32;
+        assert_eq!(
+            sect233k1().order(format!("0x{:x}", order)).build(),
+            Ok(params)
+        );
+        assert_eq!(
+            sect233k1()
+                .generator_y("0x01db537dece819b7f70f555a67c427a8cd9bf18aeb9b56e0c11056fae6a2")
+                .build(),
+            Err(Error::NotOnCurve)
+        );
+        assert_eq!(
+            sect233k1().a6("0x0").build(),
+            Err(Error::InvalidCurveParameters)
+        );
+        // 2n and 3n are multiples of the order of G, and h * n leaves the Hasse interval for any other cofactor
+        for multiple in [&order * 2u32, &order * 3u32] {
+            assert_eq!(
+                sect233k1().order(format!("0x{:x}", multiple)).build(),
+                Err(Error::InvalidCurveParameters)
+            );
+        }
+        for cofactor in [0, 1, 2, 3, 5, 8] {
+            assert_eq!(
+                sect233k1().cofactor(cofactor).build(),
+                Err(Error::InvalidCurveParameters)
+            );
+        }
+        assert_eq!(sect233k1().a6("1").build(), Err(Error::InvalidHexString));
+        assert_eq!(
+            CurveParametersBuilder::<N, Fq233>::default()
+                .a2("0x0")
+                .build(),
+            Err(Error::MissingParameter)
+        );
+    }
+
+    #[test]
+    fn test_order_checks() {
+        let n = BigUint::parse_bytes(
+            b"8000000000000000000000000000069d5bb915bcd46efb1ad5f173abdf",
+            16,
+        )
+        .unwrap();
+        assert!(is_probable_prime(&n));
+        assert!(
+            [0u32, 1, 4, 9, 561, 3215031751]
+                .iter()
+                .all(|v| !is_probable_prime(&BigUint::from(*v)))
+        );
+        assert!(!is_probable_prime(&(&n * &n)));
+        assert!(
+            [2u32, 3, 53, 59, 65537]
+                .iter()
+                .all(|v| is_probable_prime(&BigUint::from(*v)))
+        );
+        // #E lies in 2^m + 1 +- \lfloor 2^{m / 2 + 1} \rfloor
+        let q = (BigUint::one() << 233u32) + 1u32;
+        let bound = (BigUint::one() << 235u32).sqrt();
+        assert!(in_hasse_interval(&(&n * 4u32), 233));
+        assert!(in_hasse_interval(&(&q + &bound), 233));
+        assert!(!in_hasse_interval(&(&q + &bound + 1u32), 233));
+        assert!(!in_hasse_interval(&(&q - &bound - 1u32), 233));
+    }
+
+    #[test]
+    fn test_check_curve_constants() {
+        let hex = BinaryPolynomial::<N>::from_hex_str;
+        let (a2, a6) = (hex("0x0"), hex("0x1"));
+        let gx = hex("0x017232ba853a7e731af129f22ff4149563a419c26bf50a4c9d6eefad6126");
+        let gy = hex("0x01db537dece819b7f70f555a67c427a8cd9bf18aeb9b56e0c11056fae6a3");
+        let order = hex("0x8000000000000000000000000000069d5bb915bcd46efb1ad5f173abdf");
+        assert_eq!(
+            check_curve_constants(M, R, &a2, &a6, &gx, &gy, &order, 4),
+            Ok(())
+        );
+        let flipped = hex("0x01db537dece819b7f70f555a67c427a8cd9bf18aeb9b56e0c11056fae6a2");
+        assert!(check_curve_constants(M, R, &a2, &a6, &gx, &flipped, &order, 4).is_err());
+        assert!(check_curve_constants(M, R, &a2, &a2, &gx, &gy, &order, 4).is_err());
+        // x^233 is out of the field
+        let big = hex("0x020000000000000000000000000000000000000000000000000000000000");
+        assert!(check_curve_constants(M, R, &big, &a6, &gx, &gy, &order, 4).is_err());
+        let even = hex("0x8000000000000000000000000000069d5bb915bcd46efb1ad5f173abde");
+        assert!(check_curve_constants(M, R, &a2, &a6, &gx, &gy, &even, 4).is_err());
+        for cofactor in [0, 1, 2, 3, 5, 8, u64::MAX] {
+            assert!(check_curve_constants(M, R, &a2, &a6, &gx, &gy, &order, cofactor).is_err());
+        }
+    }
+}
@@ -0,0 +1,192 @@
+/// SEC1 octet string encoding of binary elliptic curve points, section 2.3.3 and 2.3.4 of "SEC 1: Elliptic Curve Cryptography"
+use super::curve::{BinaryCurve, ProjectivePoint};
+use crate::binary_field::BinaryField;
+use crate::error::Error;
+
+impl<const N: usize, Field: BinaryField<N>, Curve: BinaryCurve<N, Field>>
+    ProjectivePoint<N, Field, Curve>
+{
+    // 0x00 for the identity, otherwise 0x04 || x || y when uncompressed and (0x02 + \tilde{y}) || x when compressed,
+    // where \tilde{y} = lsb(y / x) and \tilde{y} = 0 when x = 0
+    pub fn to_sec1_bytes(&self, compressed: bool) -> Vec<u8> {
+        let Some((x, y)) = self.to_affine() else {
+            return vec![0x00];
+        };
+        if compressed {
+            let mut result = vec![0x02 | Self::y_bit(x, y)];
+            result.extend(x.to_be_bytes());
+            result
+        } else {
+            let mut result = vec![0x04];
+            result.extend(x.to_be_bytes());
+            result.extend(y.to_be_bytes());
+            result
+        }
+    }
+
+    // accepts compressed, uncompressed and hybrid (0x06 + \tilde{y}) || x || y forms,
+    // rejecting the identity and the points off curve
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let l = Field::M.div_ceil(8);
+        let (prefix, body) = bytes.split_first().ok_or(Error::InvalidEncoding)?;
+        let p = match (*prefix, body.len()) {
+            (0x00, 0) => return Err(Error::IdentityPoint),
+            (0x02 | 0x03, n) if n == l => {
+                let x = Field::from_be_bytes(body)?;
+                Self::decompress(x, prefix & 1)?
+            }
+            (0x04, n) if n == 2 * l => Self::from_affine(
+                Field::from_be_bytes(&body[..l])?,
+                Field::from_be_bytes(&body[l..])?,
+            ),
+            (0x06 | 0x07, n) if n == 2 * l => {
+                let (x, y) = (
+                    Field::from_be_bytes(&body[..l])?,
+                    Field::from_be_bytes(&body[l..])?,
+                );
+                if Self::y_bit(x, y) != prefix & 1 {
+                    return Err(Error::InvalidEncoding);
+                }
+                Self::from_affine(x, y)
+            }
+            _ => return Err(Error::InvalidEncoding),
+        };
+        if !p.is_on_curve() {
+            return Err(Error::NotOnCurve);
+        }
+        Ok(p)
+    }
+
+    // compression bit \tilde{y} = lsb(y / x), or 0 when x = 0
+    fn y_bit(x: Field, y: Field) -> u8 {
+        if x.is_zero() {
+            return 0u8;
+        }
+        (y / x).polynomial().get(0)
+    }
+
+    // y = x * z where z^2 + z = x + a_2 + a_6 / x^2 with lsb(z) = \tilde{y}, and y = \sqrt{a_6} when x = 0
+    fn decompress(x: Field, y_bit: u8) -> Result<Self, Error> {
+        if x.is_zero() {
+            return Ok(Self::from_affine(x, Curve::A6_SQRT));
+        }
+        let beta = x + Curve::A2 + Curve::A6 / x.squaring();
+        let mut z = Field::solve_quadratic(beta).ok_or(Error::NotOnCurve)?;
+        if z.polynomial().get(0) != y_bit {
+            z = z + Field::one();
+        }
+        Ok(Self::from_affine(x, x * z))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::binary_curve::curve::BinaryCurve;
+    use crate::binary_curve::{b163::B163Point, k233::K233Point};
+    use crate::binary_field::BinaryField;
+    use crate::error::Error;
+    use crate::{B163, Fn233, FnB163, Fq233, K233};
+
+    #[test]
+    fn test_sec1_encoding() {
+        // encodings of [k]G on K-233 from OpenSSL, with \tilde{y} = 0 and \tilde{y} = 1 respectively
+        let test_data = [
+            (
+                0x0a5b,
+                "0200e1b88530f409ae2e7940813fe9de6e62295b1379af51065475cfc24b22",
+                "0400e1b88530f409ae2e7940813fe9de6e62295b1379af51065475cfc24b220013588fe5ce0439df4206105c4d061043d66714c4647cb337c88d78a533",
+                "0600e1b88530f409ae2e7940813fe9de6e62295b1379af51065475cfc24b220013588fe5ce0439df4206105c4d061043d66714c4647cb337c88d78a533",
+            ),
+            (
+                0x0a5d,
+                "0300404bfe04ebb1606b10411ba297dbe9f01b8bfab6e1c408d5bd6a158d4c",
+                "0400404bfe04ebb1606b10411ba297dbe9f01b8bfab6e1c408d5bd6a158d4c01cc85ab67c2a4d86a589beab7e0b9e8a32a16377b7626bc66e8c53d63cb",
+                "0700404bfe04ebb1606b10411ba297dbe9f01b8bfab6e1c408d5bd6a158d4c01cc85ab67c2a4d86a589beab7e0b9e8a32a16377b7626bc66e8c53d63cb",
+            ),
+        ];
+        for (k, compressed, uncompressed, hybrid) in test_data {
+            let p = K233::GENERATOR * Fn233::from(k);
+            let (compressed, uncompressed, hybrid) = (
+                hex::decode(compressed).unwrap(),
+                hex::decode(uncompressed).unwrap(),
+                hex::decode(hybrid).unwrap(),
+            );
+            assert_eq!(p.to_sec1_bytes(true), compressed);
+            assert_eq!(p.to_sec1_bytes(false), uncompressed);
+            for bytes in [compressed, uncompressed, hybrid] {
+                assert_eq!(
+                    K233Point::from_sec1_bytes(&bytes),
+                    Ok(p),
+                    "Test for K233 SEC1 decoding failed!"
+                );
+            }
+        }
+        // projective points are normalized before encoding
+        let p = B163::GENERATOR * FnB163::from(0x1234) + B163::GENERATOR;
+        assert!(!p.is_affine());
+        for compressed in [true, false] {
+            assert_eq!(
+                B163Point::from_sec1_bytes(&p.to_sec1_bytes(compressed)),
+                Ok(p),
+                "Test for B163 SEC1 round trip failed!"
+            );
+        }
+        // (0, \sqrt{a_6}) is encoded with \tilde{y} = 0
+        let t = K233Point::from_affine(Fq233::zero(), K233::A6_SQRT);
+        assert_eq!(K233Point::from_sec1_bytes(&t.to_sec1_bytes(true)), Ok(t));
+    }
+
+    #[test]
+    fn test_sec1_rejection() {
+        let g = K233::GENERATOR;
+        assert_eq!(K233::IDENTITY.to_sec1_bytes(true), vec![0x00]);
+        assert_eq!(
+            K233Point::from_sec1_bytes(&[0x00]),
+            Err(Error::IdentityPoint)
+        );
+        assert_eq!(K233Point::from_sec1_bytes(&[]), Err(Error::InvalidEncoding));
+        // wrong prefix or length
+        let mut bytes = g.to_sec1_bytes(false);
+        bytes[0] = 0x05;
+        assert_eq!(
+            K233Point::from_sec1_bytes(&bytes),
+            Err(Error::InvalidEncoding)
+        );
+        let bytes = g.to_sec1_bytes(true);
+        assert_eq!(
+            K233Point::from_sec1_bytes(&bytes[..bytes.len() - 1]),
+            Err(Error::InvalidEncoding)
+        );
+        // coordinate with degree >= M
+        let mut bytes = g.to_sec1_bytes(true);
+        bytes[1] |= 0x80;
+        assert_eq!(
+            K233Point::from_sec1_bytes(&bytes),
+            Err(Error::DegreeTooLarge)
+        );
+        // hybrid form with inconsistent \tilde{y}
+        let mut bytes = g.to_sec1_bytes(false);
+        bytes[0] = 0x06 | (1 - (g.to_sec1_bytes(true)[0] & 1));
+        assert_eq!(
+            K233Point::from_sec1_bytes(&bytes),
+            Err(Error::InvalidEncoding)
+        );
+        // off-curve y, and x without any y on curve
+        let mut bytes = g.to_sec1_bytes(false);
+        let last = bytes.len() - 1;
+        bytes[last] ^= 1;
+        assert_eq!(K233Point::from_sec1_bytes(&bytes), Err(Error::NotOnCurve));
+        let off_curve = (1u64..)
+            .map(|x| {
+                let mut bytes = vec![0x02];
+                bytes.extend(Fq233::from_hex_string(&format!("0x{:x}", x)).to_be_bytes());
+                bytes
+            })
+            .find(|bytes| K233Point::from_sec1_bytes(bytes).is_err())
+            .unwrap();
+        assert_eq!(
+            K233Point::from_sec1_bytes(&off_curve),
+            Err(Error::NotOnCurve)
+        );
+    }
+}
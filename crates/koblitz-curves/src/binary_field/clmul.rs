@@ -0,0 +1,145 @@
+/// Carry-less multiplication of binary polynomials with the PCLMULQDQ instruction, detected at runtime on x86_64
+use super::polynomial::{BinaryPolynomial, BinaryPolynomial2};
+
+// whether the carry-less multiplication instruction is available on the running host
+pub fn is_available() -> bool {
+    #[cfg(target_arch = "x86_64")]
+    {
+        std::is_x86_feature_detected!("pclmulqdq")
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        false
+    }
+}
+
+// a * b with 64-bit carry-less multiplications, or None when the host lacks the instruction
+pub fn mul<const N: usize>(
+    a: &BinaryPolynomial<N>,
+    b: &BinaryPolynomial<N>,
+) -> Option<BinaryPolynomial2<N>> {
+    #[cfg(target_arch = "x86_64")]
+    if is_available() {
+        // SAFETY: pclmulqdq has been detected at runtime
+        return Some(unsafe { x86_64::mul(a, b) });
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    let _ = (a, b);
+    None
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86_64 {
+    use crate::binary_field::polynomial::{BinaryPolynomial, BinaryPolynomial2, WORD, WORD_SIZE};
+    use std::arch::x86_64::{
+        __m128i, _mm_clmulepi64_si128, _mm_cvtsi128_si64, _mm_set_epi64x, _mm_unpackhi_epi64,
+    };
+
+    // words packed into 64-bit limbs, at most N of them since a word has no more than 64 bits
+    // the cast is a no-op when the word is u64
+    #[allow(clippy::unnecessary_cast)]
+    fn to_limbs<const N: usize>(a: &BinaryPolynomial<N>) -> [u64; N] {
+        let mut limbs = [0u64; N];
+        for (i, w) in a.0.iter().enumerate() {
+            limbs[i * WORD_SIZE / 64] |= (*w as u64) << (i * WORD_SIZE % 64);
+        }
+        limbs
+    }
+
+    // schoolbook multiplication over 64-bit limbs, e.g. 16 PCLMULQDQ for a 233-bit polynomial
+    #[target_feature(enable = "pclmulqdq")]
+    #[allow(clippy::needless_range_loop)]
+    pub(super) fn mul<const N: usize>(
+        a: &BinaryPolynomial<N>,
+        b: &BinaryPolynomial<N>,
+    ) -> BinaryPolynomial2<N> {
+        let l = (N * WORD_SIZE).div_ceil(64);
+        let (a, b) = (to_limbs(a), to_limbs(b));
+        // limbs of the product, the k-th one is c[k / N][k % N]
+        let mut c = [[0u64; N]; 2];
+        for i in 0..l {
+            let ai = _mm_set_epi64x(0, a[i] as i64);
+            for j in 0..l {
+                let p: __m128i = _mm_clmulepi64_si128(ai, _mm_set_epi64x(0, b[j] as i64), 0x00);
+                let (lo, hi) = (
+                    _mm_cvtsi128_si64(p) as u64,
+                    _mm_cvtsi128_si64(_mm_unpackhi_epi64(p, p)) as u64,
+                );
+                let k = i + j;
+                c[k / N][k % N] ^= lo;
+                c[(k + 1) / N][(k + 1) % N] ^= hi;
+            }
+        }
+        let mut result = BinaryPolynomial2::<N>::zero();
+        for k in 0..2 * N {
+            let bit = k * WORD_SIZE;
+            let limb = bit / 64;
+            result[k] = (c[limb / N][limb % N] >> (bit % 64)) as WORD;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary_field::BinaryField;
+    use crate::binary_field::fq233;
+    use crate::binary_field::polynomial::WORD;
+    use crate::{Fq163, Fq233, Fq283, Fq409, Fq571};
+    use rand::Rng;
+
+    fn random<const N: usize>(rng: &mut impl Rng) -> BinaryPolynomial<N> {
+        BinaryPolynomial(std::array::from_fn(|_| rng.random::<WORD>()))
+    }
+
+    fn differential<const N: usize>(rng: &mut impl Rng) {
+        let ones = BinaryPolynomial([WORD::MAX; N]);
+        let mut inputs = vec![
+            (BinaryPolynomial::zero(), ones),
+            (BinaryPolynomial::one(), ones),
+            (ones, ones),
+        ];
+        inputs.extend((0..32).map(|_| (random::<N>(rng), random::<N>(rng))));
+        for (a, b) in inputs {
+            let expected = a.window_mul(&b);
+            assert_eq!(a.masked_mul(&b), expected);
+            if is_available() {
+                assert_eq!(
+                    mul(&a, &b),
+                    Some(expected),
+                    "Test for carry-less multiplication failed!"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_clmul() {
+        let mut rng = rand::rng();
+        differential::<1>(&mut rng);
+        differential::<3>(&mut rng);
+        differential::<6>(&mut rng);
+        differential::<8>(&mut rng);
+        differential::<9>(&mut rng);
+        differential::<13>(&mut rng);
+        differential::<18>(&mut rng);
+        // field multiplications go through the same path
+        let (a, b) = (
+            random::<{ fq233::N }>(&mut rng),
+            random::<{ fq233::N }>(&mut rng),
+        );
+        let (x, y) = (Fq233::reduce(a.into()), Fq233::reduce(b.into()));
+        assert_eq!(x * y, Fq233::reduce(x.0.window_mul(&y.0)));
+        let x = Fq163::from_hex_string("0x02fe13c0537bbc11acaa07d793de4e6d5e5c94eee8");
+        assert_eq!(x * x.inv(), Fq163::one());
+        let x = Fq283::from_hex_string(
+            "0x0503213f78ca44883f1a3b8162f188e553cd265f23c1567a16876913b0c2ac2458492836",
+        );
+        assert_eq!(x * x.inv(), Fq283::one());
+        let x = Fq409::one() << 408;
+        assert_eq!(x * x.inv(), Fq409::one());
+        let x = Fq571::one() << 570;
+        assert_eq!(x * x.inv(), Fq571::one());
+    }
+}
@@ -0,0 +1,644 @@
+/// Binary fields chosen at runtime, GF(2^m) = GF(2)[X] / f(X) for an irreducible f(X) of any weight
+use super::polynomial::{BinaryPolynomial, WORD, WORD_SIZE, xor_shifted};
+use crate::error::Error;
+use num_bigint::BigUint;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::sync::Arc;
+
+// the context of a binary field, which is shared by its elements
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynBinaryField {
+    // degree of the irreducible binary polynomial
+    m: usize,
+    // r(X) = f(X) - X^m by its exponents, in descending order
+    r: Vec<usize>,
+    // number of words of an element
+    n: usize,
+    // \sqrt(X) = X^{2^{m - 1}} (mod f(X))
+    sq: Vec<WORD>,
+    // trace mask: bit i is Tr(X^i)
+    tr: Vec<WORD>,
+}
+
+// an element of a runtime binary field, whose words have degree < m
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynElement {
+    field: Arc<DynBinaryField>,
+    value: Vec<WORD>,
+}
+
+// a binary polynomial of any degree on the heap, whose little ending words have no trailing zeros
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DynPolynomial(Vec<WORD>);
+
+impl DynBinaryField {
+    // f(X) = \sum_i X^{e_i} by its exponents in any order, rejected unless it is irreducible with degree m >= 2
+    pub fn new(exponents: &[usize]) -> Result<Arc<Self>, Error> {
+        let mut r = exponents.to_vec();
+        r.sort_unstable_by(|a, b| b.cmp(a));
+        r.dedup();
+        if r.len() < 2 || r[0] < 2 {
+            return Err(Error::NotIrreducible);
+        }
+        let m = r.remove(0);
+        let mut field = Self {
+            m,
+            r,
+            n: m.div_ceil(WORD_SIZE),
+            sq: vec![],
+            tr: vec![],
+        };
+        if !field.is_irreducible() {
+            return Err(Error::NotIrreducible);
+        }
+        field.sq = field.x_pow_2k(m - 1);
+        field.tr = field.trace_mask();
+        Ok(Arc::new(field))
+    }
+
+    pub fn m(&self) -> usize {
+        self.m
+    }
+
+    // exponents of f(X) in descending order
+    pub fn exponents(&self) -> Vec<usize> {
+        std::iter::once(self.m)
+            .chain(self.r.iter().copied())
+            .collect()
+    }
+
+    pub fn zero(self: &Arc<Self>) -> DynElement {
+        DynElement {
+            field: self.clone(),
+            value: vec![0 as WORD; self.n],
+        }
+    }
+
+    pub fn one(self: &Arc<Self>) -> DynElement {
+        let mut e = self.zero();
+        e.value[0] = 1 as WORD;
+        e
+    }
+
+    // wrap little ending words of a polynomial with degree less than m
+    pub fn from_words(self: &Arc<Self>, words: &[WORD]) -> Result<DynElement, Error> {
+        let mut e = self.zero();
+        for (i, w) in words.iter().enumerate() {
+            if *w == 0 {
+                continue;
+            }
+            if i >= self.n {
+                return Err(Error::DegreeTooLarge);
+            }
+            e.value[i] = *w;
+        }
+        if degree(&e.value).is_some_and(|d| d >= self.m) {
+            return Err(Error::DegreeTooLarge);
+        }
+        Ok(e)
+    }
+
+    // from big ending hex string starting with "0x", whose polynomial must have degree less than m
+    pub fn from_hex_string(self: &Arc<Self>, s: &str) -> Result<DynElement, Error> {
+        let hex_string = s.strip_prefix("0x").ok_or(Error::InvalidHexString)?;
+        let hex_string = if hex_string.len() % 2 == 1 {
+            format!("0{}", hex_string)
+        } else {
+            hex_string.to_string()
+        };
+        let bytes = hex::decode(hex_string).map_err(|_| Error::InvalidHexString)?;
+        let mut words = vec![0 as WORD; bytes.len().div_ceil(WORD_SIZE / 8)];
+        for (i, b) in bytes.iter().rev().enumerate() {
+            words[8 * i / WORD_SIZE] |= (*b as WORD) << (8 * i % WORD_SIZE);
+        }
+        self.from_words(&words)
+    }
+
+    // remainder of a polynomial with degree at most 2m - 2, by long division with f(X) one bit at a time
+    fn reduce(&self, mut c: Vec<WORD>) -> Vec<WORD> {
+        for i in (self.m..c.len() * WORD_SIZE).rev() {
+            if (c[i / WORD_SIZE] >> (i % WORD_SIZE)) & 1 == 1 {
+                c[i / WORD_SIZE] ^= 1 << (i % WORD_SIZE);
+                for e in self.r.iter() {
+                    let p = i - self.m + e;
+                    c[p / WORD_SIZE] ^= 1 << (p % WORD_SIZE);
+                }
+            }
+        }
+        c.truncate(self.n);
+        c
+    }
+
+    // a * b (mod f(X)) with shift-and-add
+    fn mul_words(&self, a: &[WORD], b: &[WORD]) -> Vec<WORD> {
+        let mut c = vec![0 as WORD; 2 * self.n];
+        for i in 0..self.m {
+            if (a[i / WORD_SIZE] >> (i % WORD_SIZE)) & 1 == 1 {
+                xor_shifted(&mut c, b, i);
+            }
+        }
+        self.reduce(c)
+    }
+
+    // a^2 (mod f(X)) by spreading the bits
+    fn square_words(&self, a: &[WORD]) -> Vec<WORD> {
+        let mut c = vec![0 as WORD; 2 * self.n];
+        for i in 0..self.m {
+            if (a[i / WORD_SIZE] >> (i % WORD_SIZE)) & 1 == 1 {
+                c[2 * i / WORD_SIZE] |= 1 << (2 * i % WORD_SIZE);
+            }
+        }
+        self.reduce(c)
+    }
+
+    // X^{2^k} (mod f(X))
+    fn x_pow_2k(&self, k: usize) -> Vec<WORD> {
+        let mut x = vec![0 as WORD; self.n];
+        x[0] = 1 << 1;
+        for _ in 0..k {
+            x = self.square_words(&x);
+        }
+        x
+    }
+
+    // Rabin's test, f(X) is irreducible if and only if X^{2^m} = X (mod f(X)) and gcd(X^{2^{m / q}} - X, f(X)) = 1
+    // for every prime q dividing m
+    fn is_irreducible(&self) -> bool {
+        let mut x = vec![0 as WORD; self.n];
+        x[0] = 1 << 1;
+        if self.x_pow_2k(self.m) != x {
+            return false;
+        }
+        let f = DynPolynomial::from_exponents(&self.exponents());
+        prime_factors(self.m).into_iter().all(|q| {
+            let mut g = self.x_pow_2k(self.m / q);
+            g[0] ^= 1 << 1;
+            DynPolynomial::from_words(&g).gcd(&f).is_one()
+        })
+    }
+
+    // the mask whose i-th bit is Tr(X^i), by Newton's identities p_i = \sum_{0 < j < i} a_j p_{i - j} + i a_i
+    // over the power sums p_i of the roots of f(X) = X^m + \sum_j a_j X^{m - j}
+    fn trace_mask(&self) -> Vec<WORD> {
+        let mut tr = vec![0 as WORD; self.n];
+        tr[0] = (self.m % 2) as WORD;
+        for i in 1..self.m {
+            let mut p = 0 as WORD;
+            for e in self.r.iter() {
+                let j = self.m - e;
+                if j < i {
+                    p ^= (tr[(i - j) / WORD_SIZE] >> ((i - j) % WORD_SIZE)) & 1;
+                } else if j == i {
+                    p ^= (i % 2) as WORD;
+                }
+            }
+            tr[i / WORD_SIZE] |= p << (i % WORD_SIZE);
+        }
+        tr
+    }
+}
+
+impl DynElement {
+    pub fn field(&self) -> &Arc<DynBinaryField> {
+        &self.field
+    }
+
+    // little ending words of the underlying polynomial
+    pub fn words(&self) -> &[WORD] {
+        &self.value
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.value.iter().all(|w| *w == 0)
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.value[0] == 1 && self.value[1..].iter().all(|w| *w == 0)
+    }
+
+    // to big ending hex string
+    pub fn to_hex_string(&self) -> String {
+        let bytes = self
+            .value
+            .iter()
+            .rev()
+            .map(|w| hex::encode(w.to_be_bytes()))
+            .collect::<Vec<_>>()
+            .concat();
+        format!("0x{}", bytes)
+    }
+
+    fn with_value(&self, value: Vec<WORD>) -> Self {
+        Self {
+            field: self.field.clone(),
+            value,
+        }
+    }
+
+    pub fn squaring(&self) -> Self {
+        self.with_value(self.field.square_words(&self.value))
+    }
+
+    // \sqrt(x) = x_{even} + \sqrt(X) * x_{odd}, where x_{even} and x_{odd} take the even and odd bits of x with halved exponents
+    pub fn sqrt(&self) -> Self {
+        let (mut even, mut odd) = (self.field.zero().value, self.field.zero().value);
+        for i in 0..self.field.m {
+            if (self.value[i / WORD_SIZE] >> (i % WORD_SIZE)) & 1 == 1 {
+                let half = if i % 2 == 0 { &mut even } else { &mut odd };
+                half[i / 2 / WORD_SIZE] |= 1 << (i / 2 % WORD_SIZE);
+            }
+        }
+        let odd = self.field.mul_words(&odd, &self.field.sq);
+        self.with_value(even.iter().zip(odd.iter()).map(|(a, b)| a ^ b).collect())
+    }
+
+    // Tr(x) = x + x^2 + ... + x^{2^{m - 1}}, the parity of the bits of x selected by the trace mask
+    pub fn trace(&self) -> u8 {
+        let ones = self
+            .value
+            .iter()
+            .zip(self.field.tr.iter())
+            .fold(0, |acc, (a, b)| acc ^ (a & b).count_ones());
+        (ones & 1) as u8
+    }
+
+    // inversion with extended Euclidean algorithm, Algorithm 2.48 in "Guide to Elliptic Curve Cryptography"
+    pub fn try_inv(&self) -> Result<Self, Error> {
+        if self.is_zero() {
+            return Err(Error::NotInvertible);
+        }
+        let l = (self.field.m + 1).div_ceil(WORD_SIZE);
+        let mut u = self.value.clone();
+        u.resize(l, 0);
+        let mut v = vec![0 as WORD; l];
+        for e in self.field.exponents() {
+            v[e / WORD_SIZE] |= 1 << (e % WORD_SIZE);
+        }
+        let (mut g1, mut g2) = (vec![0 as WORD; l], vec![0 as WORD; l]);
+        g1[0] = 1;
+        while degree(&u) != Some(0) {
+            let (du, dv) = (degree(&u).unwrap(), degree(&v).unwrap());
+            if du < dv {
+                std::mem::swap(&mut u, &mut v);
+                std::mem::swap(&mut g1, &mut g2);
+            }
+            let j = du.abs_diff(dv);
+            xor_shifted(&mut u, &v, j);
+            xor_shifted(&mut g1, &g2, j);
+        }
+        g1.truncate(self.field.n);
+        Ok(self.with_value(g1))
+    }
+
+    pub fn inv(&self) -> Self {
+        self.try_inv().expect("Zero is not invertible!")
+    }
+
+    // x^e with left-to-right square and multiply
+    pub fn exp(&self, e: &BigUint) -> Self {
+        let mut result = self.field.one();
+        for i in (0..e.bits()).rev() {
+            result = result.squaring();
+            if e.bit(i) {
+                result = result * self.clone();
+            }
+        }
+        result
+    }
+}
+
+impl Add for DynElement {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.field, rhs.field, "Elements of different fields!");
+        let value = self.value.iter().zip(rhs.value.iter()).map(|(a, b)| a ^ b);
+        self.with_value(value.collect())
+    }
+}
+
+impl Sub for DynElement {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + rhs
+    }
+}
+
+impl Neg for DynElement {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self
+    }
+}
+
+impl Mul for DynElement {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.field, rhs.field, "Elements of different fields!");
+        self.with_value(self.field.mul_words(&self.value, &rhs.value))
+    }
+}
+
+impl DynPolynomial {
+    pub fn zero() -> Self {
+        Self(vec![])
+    }
+
+    pub fn one() -> Self {
+        Self(vec![1 as WORD])
+    }
+
+    // \sum_i X^{e_i} by its exponents in any order
+    pub fn from_exponents(exponents: &[usize]) -> Self {
+        let mut words = vec![0 as WORD; exponents.iter().max().map_or(0, |e| e / WORD_SIZE + 1)];
+        for e in exponents {
+            words[e / WORD_SIZE] ^= 1 << (e % WORD_SIZE);
+        }
+        Self::from_words(&words)
+    }
+
+    // from little ending words, dropping the trailing zeros
+    pub fn from_words(words: &[WORD]) -> Self {
+        let l = words.iter().rposition(|w| *w != 0).map_or(0, |i| i + 1);
+        Self(words[..l].to_vec())
+    }
+
+    pub fn words(&self) -> &[WORD] {
+        &self.0
+    }
+
+    // None for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        degree(&self.0)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.0 == [1 as WORD]
+    }
+
+    // q(X) and r(X) such that self = q(X) * d(X) + r(X) with deg(r) < deg(d)
+    pub fn div_rem(&self, d: &Self) -> (Self, Self) {
+        let k = d.degree().expect("Division by zero polynomial!");
+        let mut r = self.0.clone();
+        let mut q = vec![0 as WORD; r.len()];
+        while let Some(s) = degree(&r).and_then(|dr| dr.checked_sub(k)) {
+            q[s / WORD_SIZE] |= 1 << (s % WORD_SIZE);
+            xor_shifted(&mut r[..], &d.0, s);
+        }
+        (Self::from_words(&q), Self::from_words(&r))
+    }
+
+    // gcd with Euclid's algorithm
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let (_, r) = a.div_rem(&b);
+            (a, b) = (b, r);
+        }
+        a
+    }
+
+    // (g, s, t) such that g = gcd(self, other) = s(X) * self + t(X) * other
+    pub fn xgcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (mut r0, mut r1) = (self.clone(), other.clone());
+        let (mut s0, mut s1) = (Self::one(), Self::zero());
+        let (mut t0, mut t1) = (Self::zero(), Self::one());
+        while !r1.is_zero() {
+            let (q, r) = r0.div_rem(&r1);
+            (r0, r1) = (r1, r);
+            let s = s0 + q.clone() * s1.clone();
+            (s0, s1) = (s1, s);
+            let t = t0 + q * t1.clone();
+            (t0, t1) = (t1, t);
+        }
+        (r0, s0, t0)
+    }
+
+    // self * rhs (mod m(X))
+    pub fn mul_mod(&self, rhs: &Self, m: &Self) -> Self {
+        (self.clone() * rhs.clone()).div_rem(m).1
+    }
+
+    // self^e (mod m(X)) with left-to-right square and multiply
+    pub fn pow_mod(&self, e: &BigUint, m: &Self) -> Self {
+        let base = self.div_rem(m).1;
+        let mut result = Self::one().div_rem(m).1;
+        for i in (0..e.bits()).rev() {
+            result = result.mul_mod(&result, m);
+            if e.bit(i) {
+                result = result.mul_mod(&base, m);
+            }
+        }
+        result
+    }
+
+    // self(g(X)) (mod m(X)) with Horner's rule
+    pub fn compose_mod(&self, g: &Self, m: &Self) -> Self {
+        let g = g.div_rem(m).1;
+        let mut result = Self::zero();
+        for i in (0..self.degree().map_or(0, |d| d + 1)).rev() {
+            result = result.mul_mod(&g, m);
+            if (self.0[i / WORD_SIZE] >> (i % WORD_SIZE)) & 1 == 1 {
+                result = (result + Self::one()).div_rem(m).1;
+            }
+        }
+        result
+    }
+}
+
+impl<const N: usize> From<BinaryPolynomial<N>> for DynPolynomial {
+    fn from(v: BinaryPolynomial<N>) -> Self {
+        Self::from_words(&v.0)
+    }
+}
+
+impl Add for DynPolynomial {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self::Output {
+        let (mut long, short) = if self.0.len() >= rhs.0.len() {
+            (self.0, rhs.0)
+        } else {
+            (rhs.0, self.0)
+        };
+        long.iter_mut().zip(short.iter()).for_each(|(a, b)| *a ^= b);
+        Self::from_words(&long)
+    }
+}
+
+impl Sub for DynPolynomial {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + rhs
+    }
+}
+
+// shift-and-add over the bits of self
+impl Mul for DynPolynomial {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut c = vec![0 as WORD; self.0.len() + rhs.0.len()];
+        for i in 0..self.0.len() * WORD_SIZE {
+            if (self.0[i / WORD_SIZE] >> (i % WORD_SIZE)) & 1 == 1 {
+                xor_shifted(&mut c[..], &rhs.0, i);
+            }
+        }
+        Self::from_words(&c)
+    }
+}
+
+// degree of a polynomial in words, None for the zero polynomial
+fn degree(a: &[WORD]) -> Option<usize> {
+    let i = a.iter().rposition(|w| *w != 0)?;
+    Some(i * WORD_SIZE + WORD_SIZE - 1 - a[i].leading_zeros() as usize)
+}
+
+// distinct prime factors of m
+fn prime_factors(mut m: usize) -> Vec<usize> {
+    let mut factors = vec![];
+    let mut q = 2;
+    while q * q <= m {
+        if m % q == 0 {
+            factors.push(q);
+            while m % q == 0 {
+                m /= q;
+            }
+        }
+        q += 1;
+    }
+    if m > 1 {
+        factors.push(m);
+    }
+    factors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary_field::BinaryField;
+    use crate::binary_field::fq233::Fq233;
+    use crate::binary_field::polynomial::BinaryPolynomial;
+    use rand::Rng;
+
+    fn random_fq233(rng: &mut impl Rng) -> Fq233 {
+        let v = BinaryPolynomial(std::array::from_fn(|_| rng.random::<WORD>()));
+        Fq233::reduce(v.into())
+    }
+
+    #[test]
+    fn test_irreducible() {
+        assert!(DynBinaryField::new(&[233, 74, 0]).is_ok());
+        assert!(DynBinaryField::new(&[0, 163, 3, 6, 7]).is_ok());
+        // irreducible but not primitive
+        assert!(DynBinaryField::new(&[4, 3, 2, 1, 0]).is_ok());
+        let reducible: [&[usize]; 5] = [
+            &[4, 0],
+            // (X^2 + X + 1)(X^3 + X + 1) without roots in GF(2)
+            &[5, 4, 0],
+            // (X^3 + X + 1)(X^3 + X^2 + 1) which passes X^{2^6} = X (mod f(X))
+            &[6, 5, 4, 3, 2, 1, 0],
+            &[233, 0],
+            &[0],
+        ];
+        for f in reducible {
+            assert_eq!(DynBinaryField::new(f), Err(Error::NotIrreducible));
+        }
+    }
+
+    // the same operations as Fq233 on random elements
+    #[test]
+    fn test_compare_fq233() {
+        let field = DynBinaryField::new(&[233, 74, 0]).unwrap();
+        let mut rng = rand::rng();
+        let lift = |x: &Fq233| field.from_words(&x.0.0).unwrap();
+        for _ in 0..8 {
+            let (a, b) = (random_fq233(&mut rng), random_fq233(&mut rng));
+            let (x, y) = (lift(&a), lift(&b));
+            assert_eq!(x.clone() + y.clone(), lift(&(a + b)));
+            assert_eq!(x.clone() * y.clone(), lift(&(a * b)));
+            assert_eq!(x.squaring(), lift(&a.squaring()));
+            assert_eq!(x.sqrt(), lift(&a.sqrt()));
+            assert_eq!(x.inv(), lift(&a.inv()));
+            assert_eq!(x.trace(), a.trace());
+            assert_eq!(x.to_hex_string(), a.to_hex_string());
+            let e = b.0;
+            let e_int = BigUint::parse_bytes(&e.to_hex_string().as_bytes()[2..], 16).unwrap();
+            assert_eq!(x.exp(&e_int), lift(&a.exp(e)));
+        }
+        assert_eq!(field.sq, Fq233::SQ.0.to_vec());
+        assert_eq!(field.tr, Fq233::TR.0.to_vec());
+        assert_eq!(field.zero().try_inv(), Err(Error::NotInvertible));
+    }
+
+    // a dense polynomial of even degree, where sqrt and trace only follow from their definitions
+    #[test]
+    fn test_dense_field() {
+        // X^8 + X^4 + X^3 + X + 1, the AES polynomial
+        let field = DynBinaryField::new(&[8, 4, 3, 1, 0]).unwrap();
+        let x = field.from_hex_string("0x53").unwrap();
+        assert_eq!(x.inv(), field.from_hex_string("0xca").unwrap());
+        assert_eq!(x.sqrt().squaring(), x);
+        let mut tr = x.clone();
+        let mut t = x.clone();
+        for _ in 1..field.m() {
+            t = t.squaring();
+            tr = tr + t.clone();
+        }
+        assert!(tr.is_zero() || tr.is_one());
+        assert_eq!(tr.is_one() as u8, x.trace());
+        assert_eq!(x.exp(&BigUint::from(255u32)), field.one());
+        assert_eq!(field.from_hex_string("0x1ff"), Err(Error::DegreeTooLarge));
+    }
+
+    // the same results as BinaryPolynomial below N words, and the Bezout identity with degrees beyond them
+    #[test]
+    fn test_dyn_polynomial() {
+        use crate::binary_field::fq233::N;
+        let mut rng = rand::rng();
+        for _ in 0..8 {
+            let mut random = || {
+                BinaryPolynomial::<N>(std::array::from_fn(|i| {
+                    if i < N / 2 { rng.random::<WORD>() } else { 0 }
+                }))
+            };
+            let (a, b, m) = (random(), random(), random());
+            let (x, y, z) = (
+                DynPolynomial::from(a),
+                DynPolynomial::from(b),
+                DynPolynomial::from(m),
+            );
+            let (q, r) = a.div_rem(&b);
+            assert_eq!(x.div_rem(&y), (q.into(), r.into()));
+            let (g, s, t) = a.xgcd(&b);
+            assert_eq!(x.xgcd(&y), (g.into(), s.into(), t.into()));
+            let e = BigUint::from(rng.random::<u64>());
+            assert_eq!(x.pow_mod(&e, &z), a.pow_mod(&e, &m).into());
+            assert_eq!(x.compose_mod(&y, &z), a.compose_mod(&b, &m).into());
+        }
+        // X^{2^k} - X over GF(2) is the product of the irreducible polynomials whose degrees divide k
+        let f = DynPolynomial::from_exponents(&[1 << 11, 1]);
+        let g = DynPolynomial::from_exponents(&[0, 2, 5, 9, 1000, 1999]);
+        let (d, s, t) = f.xgcd(&g);
+        assert_eq!(s * f.clone() + t * g.clone(), d);
+        assert!(f.div_rem(&d).1.is_zero() && g.div_rem(&d).1.is_zero());
+        // the primitive X^11 + X^2 + 1 divides X^{2^11} - X, and X^2 + X + 1 divides X^4 - X
+        let h = DynPolynomial::from_exponents(&[11, 2, 0]);
+        assert!(f.div_rem(&h).1.is_zero());
+        let x = DynPolynomial::from_exponents(&[1]);
+        assert_eq!(x.pow_mod(&BigUint::from(1u32 << 11), &h), x);
+        let h = DynPolynomial::from_exponents(&[2, 1, 0]);
+        assert_eq!(DynPolynomial::from_exponents(&[4, 1]).gcd(&h), h);
+    }
+}
@@ -0,0 +1,67 @@
+#![allow(non_snake_case)]
+
+/// Base binary field for K-163 and B-163 curves
+use super::BinaryField;
+use crate::define_binary_field;
+
+// binary field Fq163 = GF(2^m) / f(X), where m = 163 and f(X) = X^163 + X^7 + X^6 + X^3 + 1
+// N = 6 when word = u32, 3 when word = u64 and 21 when word = u8
+// Tr(X^i) = 1 only for i = 0 and i = 157
+// \sqrt(X) = X^{2^162} (mod f(X)), f(X) is a pentanomial
+define_binary_field!(Fq163, 163, [7, 6, 3]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fq_reduce() {
+        let v = Fq163::from_hex_string(
+            "0x000000000000000f63f65da874007cb47cc661e97589ca4a07c15471a4517d6c6694f229359b154881a0d5b3ffc6e35c",
+        );
+        assert_eq!(
+            v,
+            Fq163::from_hex_string("0x020b0edcb5fc10afefec90a9402e4ad040fae075b2")
+        );
+    }
+
+    #[test]
+    fn test_fq_arithmetic() {
+        let (u, v) = (
+            Fq163::from_hex_string("0x01205738d16018366cf658f7a75ed34fe53a096533"),
+            Fq163::from_hex_string("0x063f584ad4230824d215ceb3a10b3510b0b46ee1da"),
+        );
+        assert_eq!(
+            u * v,
+            Fq163::from_hex_string("0x045167ee09409d35512f63c838539bf01be07881a4"),
+            "Test for Fq163 multiplication failed!"
+        );
+        assert_eq!(
+            u.inv(),
+            Fq163::from_hex_string("0x027084e405d437607b739a5e2695fa831e66a84f11"),
+            "Test for Fq163 inversion failed!"
+        );
+        assert_eq!(u * u.inv(), Fq163::one());
+        assert_eq!(
+            u.sqrt(),
+            Fq163::from_hex_string("0x0592e9010a2cf08d391a6ed600814873c154663214"),
+            "Test for Fq163 sqrt failed!"
+        );
+        assert_eq!(
+            Fq163::reduce(Fq163::SQ.squaring()),
+            Fq163::one() << 1,
+            "Square root of X is not correct!"
+        );
+        // Tr(1) = m (mod 2)
+        assert_eq!(Fq163::one().trace(), 1u8);
+        // z^2 + z = c is solvable if and only if Tr(c) = 0
+        let c = u.squaring() + u;
+        let z = Fq163::solve_quadratic(c).unwrap();
+        assert_eq!(
+            z.squaring() + z,
+            c,
+            "Test for Fq163 quadratic solver failed!"
+        );
+        assert_eq!(Fq163::solve_quadratic(c + Fq163::one()), None);
+    }
+}
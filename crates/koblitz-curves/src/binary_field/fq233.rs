@@ -1,330 +1,19 @@
 #![allow(non_snake_case)]
 
 /// Base binary field for K-233 curve
-use super::{BinaryField, M, N};
-use crate::binary_field::polynomial::{BinaryPolynomial, BinaryPolynomial2, WORD_SIZE};
-use core::ops::{Add, Div, Mul, Neg, Shl, Shr, Sub};
+use super::BinaryField;
+use crate::define_binary_field;
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-pub struct Fq233(pub BinaryPolynomial<N>);
-
-#[allow(dead_code, non_snake_case)]
-impl Fq233 {
-    // convert to hex string
-    pub fn to_hex_string(&self) -> String {
-        self.0.to_hex_string()
-    }
-
-    // initiate from hex string
-    pub fn from_hex_string(s: &String) -> Self {
-        assert!(s.starts_with("0x"));
-        let hex_string = s.strip_prefix("0x").unwrap().to_string();
-        let mut result = BinaryPolynomial2::<N>::zero();
-        let hex_number_boundary = N * WORD_SIZE / 4;
-        if hex_string.len() > hex_number_boundary {
-            result.0[0] = BinaryPolynomial::<N>::from_hex_string(&format!(
-                "0x{}",
-                hex_string[(hex_string.len() - hex_number_boundary - 1)..].to_string()
-            ));
-            result.0[1] = BinaryPolynomial::<N>::from_hex_string(&format!(
-                "0x{}",
-                hex_string[0..(hex_string.len() - hex_number_boundary)].to_string()
-            ));
-        } else {
-            result.0[0] = BinaryPolynomial::<N>::from_hex_string(s);
-        }
-        Self::reduce(result)
-    }
-
-    // get bit value of binary field
-    pub fn get(&self, offset: usize) -> u8 {
-        assert!(offset < M, "offset is too big!");
-        self.0.get(offset)
-    }
-
-    // set bit value of binary field
-    pub fn set(&mut self, offset: usize, bit: u8) {
-        assert!(offset < M, "offset is too big!");
-        self.0.set(offset, bit);
-    }
-
-    // swap two binary field
-    pub fn swap(&mut self, other: &mut Self) {
-        let tmp = *self;
-        *self = *other;
-        *other = tmp;
-    }
-
-    // Modular composition of Brent and Kung, Algorithm 11.50 in "Handbook of Elliptic and HyperElliptic Curve Cryptography"
-    // compute f(X)^{2^r} (mod m(X)) = f(g(X)) (mod m(X)), where g(X) = X^{2^r}, deg(f) < M, deg(g) < M and deg(m) = M
-    fn modular_composition(&self, g: Self) -> Self {
-        let k = (M as f32).sqrt().ceil() as usize;
-        assert!(k * k <= N * WORD_SIZE, "Modular parameter N is too small!");
-        // precompute G_i[X] = 1, g, g^2, g^3, ...,g^{k - 1}
-        let mut G = vec![Fq233::zero(); k];
-        G[0] = Fq233::one();
-        for i in 1..k {
-            G[i] = g * G[i - 1];
-        }
-        let Gk = g * G[k - 1];
-        // precompute P_i[X] = 1, g^k, g^{2k}, g^{3k}, ..., g^{(k - 1)k}
-        let mut P = vec![Fq233::zero(); k];
-        P[0] = Fq233::one();
-        for i in 1..k {
-            P[i] = Gk * P[i - 1];
-        }
-        // compute F_i(X) = \sum_{j = 0}^{k - 1} f_{i * k + j} G_j[X]
-        let mut F = vec![Fq233::zero(); k];
-        for i in 0..k {
-            for j in 0..k {
-                if i * k + j >= M {
-                    continue;
-                }
-                if self.get(i * k + j) == 1u8 {
-                    F[i] = F[i] + G[j];
-                }
-            }
-        }
-        // compute R = \sum_{i = 0}^{k - 1} F_i[X] * P_i[X] (mod m(X))
-        let mut R = Fq233::zero();
-        for i in 0..k {
-            R = R + F[i] * P[i];
-        }
-        R
-    }
-
-    // Shoup exponentiation algorithm for binary field, algorithm 11.53 in "Handbook of Elliptic and HyperElliptic Curve Cryptography"
-    // compute f(X)^{n(X)} (mod m(X)) = f(X)^{n_0(X) + n_1(X) * t(X) + n_2(X) * t(X)^2 + ... + n_{l - 1}(X) * t(X)^{l - 1}}
-    pub fn exp(&self, e: BinaryPolynomial<N>) -> Self {
-        assert!(e.degree() < M, "Input parameter n is too big!");
-        let r = (M as f64 / (M as f64).log2()).ceil() as usize;
-        let n = e.chunks(r);
-        let l = n.len();
-        // precompute f^{ni}(X) (mod m(X))
-        let mut f_pow_2 = vec![Fq233::zero(); r];
-        f_pow_2[0] = *self;
-        for i in 1..r {
-            f_pow_2[i] = f_pow_2[i - 1].squaring();
-        }
-        let mut f_n = vec![Fq233::one(); l];
-        for i in 0..n.len() {
-            for j in 0..r {
-                let mask = 1 << j;
-                if n[i] & mask == mask {
-                    f_n[i] = f_n[i] * f_pow_2[j];
-                }
-            }
-        }
-        // compute g(X) = X^{2^r} (mod m(X))
-        let mut g = Fq233::one() << 1;
-        for _ in 1..(r + 1) {
-            g = g.squaring();
-        }
-        // f^n = f^{n_0 + t * n_1 + t^2 * n_2 + ... + t^{l - 1} * n_{l - 1}}
-        let mut y = Fq233::one();
-        for i in (0..l).rev() {
-            y = y.modular_composition(g);
-            y = y * f_n[i];
-        }
-        y
-    }
-
-    // Algorithm 2.48 in "Guide to Elliptic Curve Cryptography"
-    // Euclidean based binary field inversion
-    pub fn inv(&self) -> Self {
-        assert!(*self != Self::zero(), "Zero can not be inversed!");
-        if self.is_one() {
-            return Self::one();
-        }
-        let (mut u, mut v) = (
-            BinaryPolynomial2::<N>::from(self.0),
-            BinaryPolynomial2::<N>::from(Self::F),
-        );
-        let (mut g1, mut g2) = (Fq233::one(), Fq233::zero());
-        while u.is_one() == false {
-            let mut j = u.degree() as i32 - v.degree() as i32;
-            if j < 0 {
-                u.swap(&mut v);
-                g1.swap(&mut g2);
-                j = -j;
-            }
-            u = u + (v << j as usize);
-            g1 = g1 + (g2 << j as usize);
-        }
-        g1
-    }
-
-    pub fn degree(&self) -> usize {
-        assert!(self.0.degree() < M - 1, "Degree Invalid");
-        self.0.degree()
-    }
-}
-
-impl Div for Fq233 {
-    type Output = Self;
-
-    fn div(self, rhs: Self) -> Self::Output {
-        assert!(rhs != Self::zero(), "Denominator should not be zero!");
-        self * rhs.inv()
-    }
-}
-
-impl Add for Fq233 {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        if self == Self::zero() {
-            return rhs;
-        }
-        if rhs == Self::zero() {
-            return self;
-        }
-        Self(self.0 + rhs.0)
-    }
-}
-
-impl Shl<usize> for Fq233 {
-    type Output = Self;
-
-    fn shl(self, shift: usize) -> Self::Output {
-        Self::reduce(BinaryPolynomial2::<N>::from(self.0) << shift)
-    }
-}
-
-impl Shr<usize> for Fq233 {
-    type Output = Self;
-
-    fn shr(self, shift: usize) -> Self::Output {
-        Self::reduce(BinaryPolynomial2::<N>::from(self.0) >> shift)
-    }
-}
-
-impl Mul for Fq233 {
-    type Output = Self;
-
-    fn mul(self, rhs: Self) -> Self::Output {
-        if (self == Self::zero()) || (rhs == Self::zero()) {
-            return Self::zero();
-        }
-        if self == Self::one() {
-            return rhs;
-        }
-        if rhs == Self::one() {
-            return self;
-        }
-        if self == rhs {
-            self.squaring()
-        } else {
-            Self::reduce(self.0 * rhs.0)
-        }
-    }
-}
-
-impl Neg for Fq233 {
-    type Output = Self;
-
-    fn neg(self) -> Self::Output {
-        Self(-self.0)
-    }
-}
-
-impl Sub<Self> for Fq233 {
-    type Output = Self;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        if self == Self::zero() {
-            return -rhs;
-        }
-        if rhs == Self::zero() {
-            return self;
-        }
-        Self(self.0 - rhs.0)
-    }
-}
-
-impl BinaryField<N> for Fq233 {
-    // f(X) = X^233 + r(X), where r(X) = X^74 + 1
-    const F: BinaryPolynomial<N> = BinaryPolynomial([1, 0, 1024, 0, 0, 0, 0, 512]);
-    // \sqrt(X) = X^228 + X^191 + X^154 + X^117 + X^69 + X^32
-    const SQ: BinaryPolynomial<N> =
-        BinaryPolynomial([0, 1, 32, 2097152, 67108864, 2147483648, 0, 16]);
-
-    // Algorithm 2.42 in "Guide to Elliptic Curve Cryptography"
-    fn reduce(ele: BinaryPolynomial2<N>) -> Self {
-        assert!(
-            ele.degree() <= 2 * M - 2,
-            "Degree of binary polynomial is too big."
-        );
-        let mut C = ele.clone();
-        for i in (N..2 * N).rev() {
-            C[i - 8] = C[i - 8] ^ (C[i] << 23);
-            C[i - 7] = C[i - 7] ^ (C[i] >> 9);
-            C[i - 5] = C[i - 5] ^ (C[i] << 1);
-            C[i - 4] = C[i - 4] ^ (C[i] >> 31);
-        }
-        let T = C[7] >> 9;
-        C[0] = C[0] ^ T;
-        C[2] = C[2] ^ (T << 10);
-        C[3] = C[3] ^ (T >> 22);
-        C[7] = C[7] & 0x1ff;
-
-        Self(C.lower())
-    }
-    // trivial checks
-    fn is_zero(&self) -> bool {
-        *self == Self::zero()
-    }
-    fn is_one(&self) -> bool {
-        *self == Self::one()
-    }
-    fn is_power_of_2(&self) -> bool {
-        let ones = self.bits(true).iter().fold(0, |acc, v| acc + v);
-        (ones == 1u8) || (ones == 0u8)
-    }
-    // convert to little ending bits and remove leading zeros if necessary
-    fn bits(&self, remove: bool) -> Vec<u8> {
-        self.0.to_le_bits(remove)
-    }
-    // square root of binary field
-    // \sqrt(f(X)) = f_{even} + \sqrt(X) * f_{odd}, where \sqrt(X) is constant
-    fn sqrt(&self) -> Self {
-        let pair = self.0.split();
-        Self(pair[0]) + Self::reduce(pair[1] * Self::SQ)
-    }
-    // squaring of binary field
-    fn squaring(&self) -> Self {
-        if self.is_zero() {
-            return Self::zero();
-        }
-        if self.is_one() {
-            return Self::one();
-        }
-        Self::reduce(self.0.squaring())
-    }
-    // trace of a binary field
-    // Tr(x) = x + x^2 + x^{2^2} + x^{2^3} + ... + x^{2^{M - 1}}
-    fn trace(&self) -> Self {
-        let mut result = *self;
-        let mut sq = *self;
-        for _ in 1..M {
-            sq = sq.squaring();
-            result = result + sq;
-        }
-        result
-    }
-
-    fn zero() -> Self {
-        Self(BinaryPolynomial::<N>::zero())
-    }
-
-    fn one() -> Self {
-        Self(BinaryPolynomial::<N>::one())
-    }
-}
+// binary field Fq233 = GF(2^m) / f(X), where m = 233 and f(X) = X^233 + X^74 + 1
+// N = 8 when word = u32, 4 when word = u64 and 30 when word = u8
+// Tr(X^i) = 1 only for i = 0 and i = 159
+// \sqrt(X) = X^228 + X^191 + X^154 + X^117 + X^69 + X^32
+define_binary_field!(Fq233, 233, [74]);
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::binary_field::polynomial::BinaryPolynomial;
     use std::str::FromStr;
 
     #[test]
@@ -337,7 +26,11 @@ mod tests {
         )];
         for (v_hex_string, v_reduced_hex_string) in test_data {
             let v = Fq233::from_hex_string(&v_hex_string);
-            assert_eq!(v.to_hex_string(), v_reduced_hex_string);
+            // the leading zeros depend on the word size
+            assert_eq!(
+                v.to_hex_string().trim_start_matches(['0', 'x']),
+                v_reduced_hex_string.trim_start_matches(['0', 'x'])
+            );
         }
     }
 
@@ -359,7 +52,10 @@ mod tests {
             );
             let w = u * v;
             assert_eq!(w, w_expected, "Test for Fq233 multiplication failed!");
-            assert_eq!(w.to_hex_string(), w_expected_hex_string);
+            assert_eq!(
+                w.to_hex_string().trim_start_matches(['0', 'x']),
+                w_expected_hex_string.trim_start_matches(['0', 'x'])
+            );
         }
     }
 
@@ -408,6 +104,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_fq_try_from() {
+        use crate::error::Error;
+        assert_eq!(Fq233::try_from("1234"), Err(Error::InvalidHexString));
+        assert_eq!(Fq233::try_from("0x"), Err(Error::InvalidHexString));
+        assert_eq!(Fq233::try_from("0x12g4"), Err(Error::InvalidHexString));
+        assert_eq!(Fq233::try_from("0x+1234"), Err(Error::InvalidHexString));
+        // degree 465 = 2M - 1 is beyond reduction, while leading zeros and odd length are accepted
+        let too_large = format!("0x2{}", "0".repeat(116));
+        assert_eq!(
+            Fq233::try_from(too_large.as_str()),
+            Err(Error::DegreeTooLarge)
+        );
+        let largest = format!("0x0001{}", "0".repeat(116));
+        assert_eq!(
+            Fq233::try_from(largest.as_str()),
+            Ok(Fq233::one() << 464),
+            "Test for Fq233 parsing failed!"
+        );
+        assert_eq!(Fq233::try_from("0x3"), Ok(Fq233::from_hex_string("0x03")));
+        assert_eq!(Fq233::try_from("0x000"), Ok(Fq233::zero()));
+        // zero has no inverse
+        let u =
+            Fq233::from_hex_string("0x3bd4f59063516f81a1621a4d4885e77e0f4693f893b656abe82c4e5c2f");
+        assert_eq!(Fq233::zero().try_inv(), Err(Error::NotInvertible));
+        assert_eq!(u.checked_div(Fq233::zero()), Err(Error::DivisionByZero));
+        assert_eq!(u.checked_div(u), Ok(Fq233::one()));
+    }
+
     #[test]
     fn test_modular_composition() {
         let test_data = [
@@ -547,7 +272,232 @@ mod tests {
         for u_hex_string in test_data {
             let u = Fq233::from_hex_string(&u_hex_string);
             let w = u.trace();
-            assert_eq!(w, Fq233::zero(), "Test for trace of binary field failed!");
+            assert_eq!(w, 0u8, "Test for trace of binary field failed!");
+        }
+        // trace mask and half trace table agree with their definitions
+        let mut u = Fq233::from_hex_string(
+            "0x000001ecfca5ace9b696238406aab3cf75090c2e7a4ae879be9f29bea5e704b6",
+        );
+        for _ in 0..8 {
+            let (mut tr, mut sq) = (u, u);
+            for _ in 1..M {
+                sq = sq.squaring();
+                tr = tr + sq;
+            }
+            let (mut ht, mut sq) = (u, u);
+            for _ in 0..(M - 1) / 2 {
+                sq = sq.squaring().squaring();
+                ht = ht + sq;
+            }
+            let tr_expected = if u.trace() == 1u8 {
+                Fq233::one()
+            } else {
+                Fq233::zero()
+            };
+            assert_eq!(tr, tr_expected, "Test for trace mask failed!");
+            assert_eq!(ht, u.half_trace(), "Test for half trace failed!");
+            u = u * u.sqrt() + Fq233::one();
+        }
+    }
+
+    #[test]
+    fn test_solve_quadratic() {
+        let u = Fq233::from_hex_string(
+            "0x000001ecfca5ace9b696238406aab3cf75090c2e7a4ae879be9f29bea5e704b6",
+        );
+        let c = u.squaring() + u;
+        let z = Fq233::solve_quadratic(c).unwrap();
+        assert!(z == u || z == u + Fq233::one());
+        assert_eq!(Fq233::solve_quadratic(Fq233::zero()), Some(Fq233::zero()));
+        // Tr(1) = 1 for odd M
+        assert_eq!(Fq233::solve_quadratic(c + Fq233::one()), None);
+    }
+
+    #[test]
+    fn test_conditional_select() {
+        let (mut a, mut b) = (
+            Fq233::from_hex_string(
+                "0x01ecfca5ace9b696238406aab3cf75090c2e7a4ae879be9f29bea5e704b6",
+            ),
+            Fq233::one() << 100,
+        );
+        assert_eq!(Fq233::conditional_select(&a, &b, 0u8), a);
+        assert_eq!(Fq233::conditional_select(&a, &b, 1u8), b);
+        let (a0, b0) = (a, b);
+        Fq233::conditional_swap(&mut a, &mut b, 0u8);
+        assert_eq!((a, b), (a0, b0));
+        Fq233::conditional_swap(&mut a, &mut b, 1u8);
+        assert_eq!((a, b), (b0, a0));
+    }
+
+    // equal sequences of traced operations for secrets of different weights, not a timing measurement
+    #[cfg(feature = "constant-time")]
+    #[test]
+    fn test_constant_time_trace() {
+        use crate::binary_field::op_trace::trace;
+        // secret inputs of weight 0, 1 and M, and a random one
+        let inputs = [
+            Fq233::zero(),
+            Fq233::one(),
+            Fq233::from_hex_string(
+                "0x01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            ),
+            Fq233::from_hex_string(
+                "0x01ecfca5ace9b696238406aab3cf75090c2e7a4ae879be9f29bea5e704b6",
+            ),
+        ];
+        let traces = |x: Fq233| {
+            let y = Fq233::one() << 5;
+            [
+                trace(|| x + y),
+                trace(|| x - y),
+                trace(|| x * y),
+                trace(|| y * x),
+                trace(|| x * x),
+                trace(|| x.squaring()),
+                trace(|| x.sqrt()),
+                trace(|| x.try_inv()),
+                trace(|| {
+                    let (mut a, mut b) = (x, y);
+                    Fq233::conditional_swap(&mut a, &mut b, x.get(0));
+                    (a, b)
+                }),
+            ]
+        };
+        let expected = traces(inputs[0]);
+        assert!(expected.iter().all(|t| !t.is_empty()));
+        for x in inputs {
+            assert_eq!(traces(x), expected, "Test for constant-time trace failed!");
         }
+        // the fixed addition chain still inverts
+        let x = inputs[3];
+        assert_eq!(x * x.inv(), Fq233::one());
+        assert_eq!(
+            Fq233::zero().try_inv(),
+            Err(crate::error::Error::NotInvertible)
+        );
+    }
+
+    // the branch-free operations agree with the default ones and go through the same operations in any build
+    #[test]
+    fn test_ct_operations() {
+        use crate::binary_field::op_trace::trace;
+        let inputs = [
+            Fq233::zero(),
+            Fq233::one(),
+            Fq233::from_hex_string(
+                "0x01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            ),
+            Fq233::from_hex_string(
+                "0x01ecfca5ace9b696238406aab3cf75090c2e7a4ae879be9f29bea5e704b6",
+            ),
+        ];
+        let y = Fq233::from_hex_string(
+            "0x0195c34710637a9ac9c7132a9e270a30837b6a69daa6c54f5b27d2b0b608",
+        );
+        for x in inputs {
+            assert_eq!(x.ct_add(&y), x + y);
+            assert_eq!(x.ct_mul(&y), x * y);
+            assert_eq!(x.ct_mul(&x), x * x);
+            assert_eq!(x.ct_squaring(), x.squaring());
+            assert_eq!(x.ct_inv(), x.try_inv().unwrap_or(Fq233::zero()));
+        }
+        let traces = |x: Fq233| {
+            [
+                trace(|| x.ct_add(&y)),
+                trace(|| x.ct_mul(&y)),
+                trace(|| x.ct_mul(&x)),
+                trace(|| x.ct_squaring()),
+                trace(|| x.ct_inv()),
+            ]
+        };
+        let expected = traces(inputs[0]);
+        assert!(expected.iter().all(|t| !t.is_empty()));
+        for x in inputs {
+            assert_eq!(
+                traces(x),
+                expected,
+                "Test for branch-free operations failed!"
+            );
+        }
+    }
+
+    #[test]
+    fn test_inv_itoh_tsujii() {
+        assert_eq!(
+            crate::binary_field::itoh_tsujii_chain(M),
+            vec![1, 2, 3, 6, 7, 14, 28, 29, 58, 116, 232]
+        );
+        let mut x = Fq233::from_hex_string(
+            "0x01ecfca5ace9b696238406aab3cf75090c2e7a4ae879be9f29bea5e704b6",
+        );
+        for _ in 0..8 {
+            // looked up in tables or squared k times
+            for k in [0, 1, 3, 7, 14, 29, 58, 116, 200, M] {
+                let mut expected = x;
+                for _ in 0..k {
+                    expected = expected.squaring();
+                }
+                assert_eq!(
+                    x.multi_square(k),
+                    expected,
+                    "Test for multi-squaring failed!"
+                );
+            }
+            assert_eq!(
+                x.inv_itoh_tsujii(),
+                Ok(x.inv()),
+                "Test for Itoh-Tsujii inversion failed!"
+            );
+            x = x * x + Fq233::one();
+        }
+        assert_eq!(Fq233::one().inv_itoh_tsujii(), Ok(Fq233::one()));
+        assert_eq!(
+            Fq233::zero().inv_itoh_tsujii(),
+            Err(crate::error::Error::NotInvertible)
+        );
+    }
+
+    #[test]
+    fn test_batch_invert() {
+        let mut x = Fq233::from_hex_string(
+            "0x01ecfca5ace9b696238406aab3cf75090c2e7a4ae879be9f29bea5e704b6",
+        );
+        let mut elements = vec![Fq233::zero()];
+        for _ in 0..6 {
+            elements.push(x);
+            x = x * x + Fq233::one();
+        }
+        elements.extend([Fq233::one(), Fq233::zero(), x]);
+        let mut inverses = elements.clone();
+        Fq233::batch_invert(&mut inverses);
+        for (x, x_inv) in elements.iter().zip(inverses.iter()) {
+            if x.is_zero() {
+                assert!(x_inv.is_zero());
+            } else {
+                assert_eq!(*x_inv, x.inv(), "Test for batch inversion failed!");
+            }
+        }
+        Fq233::batch_invert(&mut []);
+    }
+
+    #[test]
+    fn test_unreduced() {
+        use crate::binary_field::unreduced::Unreduced;
+        let a = Fq233::from_hex_string(
+            "0x01ecfca5ace9b696238406aab3cf75090c2e7a4ae879be9f29bea5e704b6",
+        );
+        let (b, c, d) = (a.squaring() + Fq233::one(), a.inv(), Fq233::one() << 232);
+        let sum = Unreduced::product(&a, &b).mul_add(&c, &d) + Unreduced::square(&d) + a;
+        assert_eq!(
+            sum.reduce(),
+            a * b + c * d + d.squaring() + a,
+            "Test for unreduced products failed!"
+        );
+        assert_eq!(Unreduced::<N, Fq233>::zero().reduce(), Fq233::zero());
+        assert_eq!(
+            Unreduced::<N, Fq233>::from(a).polynomial(),
+            crate::binary_field::polynomial::BinaryPolynomial2::<N>::from(a.polynomial())
+        );
     }
 }
@@ -0,0 +1,79 @@
+#![allow(non_snake_case)]
+
+/// Base binary field for K-283 and B-283 curves
+use super::BinaryField;
+use crate::define_binary_field;
+
+// binary field Fq283 = GF(2^m) / f(X), where m = 283 and f(X) = X^283 + X^12 + X^7 + X^5 + 1
+// N = 9 when word = u32, 5 when word = u64 and 36 when word = u8
+// Tr(X^i) = 1 only for i = 0 and i = 271
+// \sqrt(X) = X^{2^282} (mod f(X)), f(X) is a pentanomial
+define_binary_field!(Fq283, 283, [12, 7, 5]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fq_reduce() {
+        let v = Fq283::from_hex_string(
+            "0x00149ae1f3332eb05b6659eab3bfcd5d50545214b0afb81e8824918818fd64f799ef936ac3a8db5628865529228dc5196d16328fe0c99f3edae3df9c5b507a368eac871f492091f2",
+        );
+        assert_eq!(
+            v,
+            Fq283::from_hex_string(
+                "0x073df9f1edd2f135016a9ec583b599c95a03a53ffa485ea6a78cdb237b338dca152e0c8f"
+            )
+        );
+    }
+
+    #[test]
+    fn test_fq_arithmetic() {
+        let (u, v) = (
+            Fq283::from_hex_string(
+                "0x018d0353b3aa75ab7d1944ff09974b85f2306d4a8a2ad16e107ac8069b51c6322463278e",
+            ),
+            Fq283::from_hex_string(
+                "0x038fa3f2e18692e295990881ba9be85a74cda9c49436d6f6dc3d716bf22ff5fd25f0f212",
+            ),
+        );
+        assert_eq!(
+            u * v,
+            Fq283::from_hex_string(
+                "0x0117eeb05e94f3b45c81af7c9b25f5557ea4cb0281ad6c518e5add7ab76ff13f50ed32e7"
+            ),
+            "Test for Fq283 multiplication failed!"
+        );
+        assert_eq!(
+            u.inv(),
+            Fq283::from_hex_string(
+                "0x01fde027979a8dde1dfaed38191b23d120bb48b35d879bf59fc0d7b39c4c75e8af9116ae"
+            ),
+            "Test for Fq283 inversion failed!"
+        );
+        assert_eq!(u * u.inv(), Fq283::one());
+        assert_eq!(
+            u.sqrt(),
+            Fq283::from_hex_string(
+                "0x07f5c6c45e1a66b3f70842bd72cc93ff68e6d0d4396db07a6d10e7a0f828a634dd9303c5"
+            ),
+            "Test for Fq283 sqrt failed!"
+        );
+        assert_eq!(
+            Fq283::reduce(Fq283::SQ.squaring()),
+            Fq283::one() << 1,
+            "Square root of X is not correct!"
+        );
+        // Tr(1) = m (mod 2)
+        assert_eq!(Fq283::one().trace(), 1u8);
+        // z^2 + z = c is solvable if and only if Tr(c) = 0
+        let c = u.squaring() + u;
+        let z = Fq283::solve_quadratic(c).unwrap();
+        assert_eq!(
+            z.squaring() + z,
+            c,
+            "Test for Fq283 quadratic solver failed!"
+        );
+        assert_eq!(Fq283::solve_quadratic(c + Fq283::one()), None);
+    }
+}
@@ -0,0 +1,79 @@
+#![allow(non_snake_case)]
+
+/// Base binary field for K-409 and B-409 curves
+use super::BinaryField;
+use crate::define_binary_field;
+
+// binary field Fq409 = GF(2^m) / f(X), where m = 409 and f(X) = X^409 + X^87 + 1
+// N = 13 when word = u32, 7 when word = u64 and 52 when word = u8
+// Tr(X^i) = 1 only for i = 0
+// \sqrt(X) = X^205 + X^44
+define_binary_field!(Fq409, 409, [87]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fq_reduce() {
+        let v = Fq409::from_hex_string(
+            "0x0000571ed61e17ed57813f856165598cc3a90b68cbe20b1103623ae41dd1d6164ccfbbbf2922071dc437b057f91b60e533a9b4bb068fe849f98f7a0dda23cea1a9c59a2deebb31672a8ae1661dc35c7f65718ddca84e090a2cdd3fbe6abb3e13e4373a7db494d2a8",
+        );
+        assert_eq!(
+            v,
+            Fq409::from_hex_string(
+                "0x00fd3141c66ece7c28448ed55bb1d2f53d4af08748bc87086696afb6fc976f9edcb4efd9244ccf8ae6e9f635aa45b54e90697f87"
+            )
+        );
+    }
+
+    #[test]
+    fn test_fq_arithmetic() {
+        let (u, v) = (
+            Fq409::from_hex_string(
+                "0x00b03b4d384ef90a8b80eb31b3880de0e9be9f8881e6187fdb522231e7397785cee116191248a2a4a834d5808281a6bf48cb74a9",
+            ),
+            Fq409::from_hex_string(
+                "0x01ab2b7e4c60ded1607e39d14138cad26c64107f089d8567444fc6f938443e4f57de014c4bb36ec8030cf05de86c68cd3e6f54d4",
+            ),
+        );
+        assert_eq!(
+            u * v,
+            Fq409::from_hex_string(
+                "0x002e5c546f85e5e6dd91f4c4bee2e288fcee6c26843770d60b5bae9b28396ccd92aeb9ad89944c65e12c7991a765db3189d69711"
+            ),
+            "Test for Fq409 multiplication failed!"
+        );
+        assert_eq!(
+            u.inv(),
+            Fq409::from_hex_string(
+                "0x007d535eea8255bca8e3633243074e7afc9a05f5c3a1b87594f414b47f10860118e06c19f0bcd70be971ac203a7c8b361f234693"
+            ),
+            "Test for Fq409 inversion failed!"
+        );
+        assert_eq!(u * u.inv(), Fq409::one());
+        assert_eq!(
+            u.sqrt(),
+            Fq409::from_hex_string(
+                "0x018e4c7c771e9b459df751a4f62a9acb1782425b9c563525dee689f9842bb8472b2ddb15918efd287bbb20e8bab6e6f0012789e1"
+            ),
+            "Test for Fq409 sqrt failed!"
+        );
+        assert_eq!(
+            Fq409::reduce(Fq409::SQ.squaring()),
+            Fq409::one() << 1,
+            "Square root of X is not correct!"
+        );
+        // Tr(1) = m (mod 2)
+        assert_eq!(Fq409::one().trace(), 1u8);
+        // z^2 + z = c is solvable if and only if Tr(c) = 0
+        let c = u.squaring() + u;
+        let z = Fq409::solve_quadratic(c).unwrap();
+        assert_eq!(
+            z.squaring() + z,
+            c,
+            "Test for Fq409 quadratic solver failed!"
+        );
+        assert_eq!(Fq409::solve_quadratic(c + Fq409::one()), None);
+    }
+}
@@ -0,0 +1,79 @@
+#![allow(non_snake_case)]
+
+/// Base binary field for K-571 and B-571 curves
+use super::BinaryField;
+use crate::define_binary_field;
+
+// binary field Fq571 = GF(2^m) / f(X), where m = 571 and f(X) = X^571 + X^10 + X^5 + X^2 + 1
+// N = 18 when word = u32, 9 when word = u64 and 72 when word = u8
+// Tr(X^i) = 1 only for i = 0, 561 and 569
+// \sqrt(X) = X^{2^570} (mod f(X)), f(X) is a pentanomial
+define_binary_field!(Fq571, 571, [10, 5, 2]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fq_reduce() {
+        let v = Fq571::from_hex_string(
+            "0x000378801f3cd5cc618ef7c4278e96b5ca4b3bc77922d4e0cfb67d0f7f4906cc3956f6809afb9c5133514fc31556bd9f1ef6a9757107b5746e58190df84951fd5667de8a2d842c192c066807947b65bfd1021adffd8ec734cefa6d8cc266970ade7075cdf714e901e2061071087aa5e69e04c484e00bbe3f0f87ce0635c7ee337fbfbf6cd5ef05dea55631eb00c0b01b",
+        );
+        assert_eq!(
+            v,
+            Fq571::from_hex_string(
+                "0x047737e9743a3b42679a0311ba177964130c57aa96759f9114f2d1c41dca7ad44339cf390a9eda3cbe545b92e5bb31532419375752841c1cf452d3ea75d49ab52a7d9bcc74ef1124"
+            )
+        );
+    }
+
+    #[test]
+    fn test_fq_arithmetic() {
+        let (u, v) = (
+            Fq571::from_hex_string(
+                "0x01303216b54b1c5a7cfd3fcc8142a736efbbf85fcec56a512756064e5c33b37c86fc1635855c9ee6c3a7db151b451e20a9c8510115d3542d08b664e518593dfbb783171249eb2577",
+            ),
+            Fq571::from_hex_string(
+                "0x02eda889f707c19e534fac5659f840584ca632b1be6686fa1ee966aa92dc1b6aa88045e062e6b76c573e304f23f0a53ee9a12142045652ac308edbda101f0ed69abccb10fae5f845",
+            ),
+        );
+        assert_eq!(
+            u * v,
+            Fq571::from_hex_string(
+                "0x05394898f1578036a4b383a2853a629cb90b4393ef2b6eafda1e3509c0f6c231c6c9d5ecd103c487187073ab68e7083ba378b319bd61eaf5d5ab886cd8586ba76577fbb84e51d7a7"
+            ),
+            "Test for Fq571 multiplication failed!"
+        );
+        assert_eq!(
+            u.inv(),
+            Fq571::from_hex_string(
+                "0x0762d4ba082166e22b312f91564a8fb6db3290d168f4f90635d43bc10d1e2fa8a6fb27e0abc6e660cf9361733daadc8b84eab8b28d009831ad7affb0156821dbb43b1d1ad05a4dee"
+            ),
+            "Test for Fq571 inversion failed!"
+        );
+        assert_eq!(u * u.inv(), Fq571::one());
+        assert_eq!(
+            u.sqrt(),
+            Fq571::from_hex_string(
+                "0x07ca54636b3626544d41d49dd30add77603e3b68b5c9b6be7de57c30805dafc68397e3722d0cf50189bed2bf1df538375f714bb229a0537c2ae18cafa47efbf30d1eb5ad0388f485"
+            ),
+            "Test for Fq571 sqrt failed!"
+        );
+        assert_eq!(
+            Fq571::reduce(Fq571::SQ.squaring()),
+            Fq571::one() << 1,
+            "Square root of X is not correct!"
+        );
+        // Tr(1) = m (mod 2)
+        assert_eq!(Fq571::one().trace(), 1u8);
+        // z^2 + z = c is solvable if and only if Tr(c) = 0
+        let c = u.squaring() + u;
+        let z = Fq571::solve_quadratic(c).unwrap();
+        assert_eq!(
+            z.squaring() + z,
+            c,
+            "Test for Fq571 quadratic solver failed!"
+        );
+        assert_eq!(Fq571::solve_quadratic(c + Fq571::one()), None);
+    }
+}
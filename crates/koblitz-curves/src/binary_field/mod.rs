@@ -1,17 +1,23 @@
-#![allow(dead_code)]
+#![allow(dead_code, non_snake_case)]
+pub mod clmul;
+pub mod dynamic;
+pub mod fq163;
 pub mod fq233;
+pub mod fq283;
+pub mod fq409;
+pub mod fq571;
+#[cfg(test)]
+pub(crate) mod op_trace;
 pub mod polynomial;
+pub mod unreduced;
 pub mod word;
 
-// binary field Fq233 = GF(2^m) / f(X), where m = 233 and f(X) = X^233 + X^74 + 1
-// N = 8 when word = u32
-pub const M: usize = 233;
-pub const N: usize = 8;
-
-use polynomial::{BinaryPolynomial, BinaryPolynomial2};
+use crate::error::Error;
+use polynomial::{BinaryPolynomial, BinaryPolynomial2, WORD};
 use std::fmt::Debug;
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::ops::{Add, Div, Mul, Neg, Shl, Sub};
 
+// binary field GF(2^M) = GF(2)[X] / f(X), whose elements are binary polynomials with N words and degree < M
 pub trait BinaryField<const N: usize>:
     Debug
     + Eq
@@ -23,24 +29,802 @@ pub trait BinaryField<const N: usize>:
     + Mul<Self, Output = Self>
     + Div<Self, Output = Self>
     + Neg<Output = Self>
+    + Shl<usize, Output = Self>
+    + 'static
 {
+    // degree of the irreducible binary polynomial
+    const M: usize;
     // irreducible binary polynomial: f(X) = X^M + R(X) where M is the degree of binary polynomial, and R(X) is residual polynomial
     // which M <= N * WORD_SIZE, and deg(R) < M
     const F: BinaryPolynomial<N>;
-    // sqrt(X) = X^{(M + 1) / 2} + X^((k + 1) / 2) when irreducible polynomial m(X) is a trinomial X^M + x^k + 1 and k is a odd number
+    // trace mask: bit i is Tr(X^i), so Tr(x) is the parity of bits of x selected by the mask since trace is linear
+    const TR: BinaryPolynomial<N>;
+    // sqrt(X) = X^{(M + 1) / 2} + X^((k + 1) / 2) when irreducible polynomial m(X) is a trinomial X^M + x^k + 1 and k is a odd number,
+    // otherwise sqrt(X) = X^{2^{M - 1}} (mod f(X))
     const SQ: BinaryPolynomial<N>;
     // reduce a big binary polynomial with a fixed irreducible binary polynomial with degree M
     fn reduce(element: BinaryPolynomial2<N>) -> Self;
-    fn one() -> Self;
-    fn zero() -> Self;
-    fn is_zero(&self) -> bool;
-    fn is_one(&self) -> bool;
-    fn is_power_of_2(&self) -> bool;
-    fn bits(&self, remove: bool) -> Vec<u8>;
-    fn sqrt(&self) -> Self;
-    fn squaring(&self) -> Self;
-    fn trace(&self) -> Self;
+    // reduce without checking the degree of element first, since the check scans the words from the top
+    fn reduce_unchecked(element: BinaryPolynomial2<N>) -> Self;
+    // wrap a binary polynomial whose degree is already less than M
+    fn from_polynomial(v: BinaryPolynomial<N>) -> Self;
+    // underlying binary polynomial with degree less than M
+    fn polynomial(&self) -> BinaryPolynomial<N>;
+    // H(X^i) for odd i < M, built once per field by half_trace_table()
+    fn half_trace_table() -> &'static [Self];
+    // (k, x^{2^k} table) for the multi-squarings of Itoh-Tsujii inversion, built once per field by multi_square_tables()
+    fn multi_square_tables() -> &'static [(usize, Vec<Self>)];
+
+    fn one() -> Self {
+        Self::from_polynomial(BinaryPolynomial::<N>::one())
+    }
+    fn zero() -> Self {
+        Self::from_polynomial(BinaryPolynomial::<N>::zero())
+    }
+    // trivial checks
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+    fn is_one(&self) -> bool {
+        *self == Self::one()
+    }
+    fn is_power_of_2(&self) -> bool {
+        let ones = self.bits(true).iter().filter(|b| **b == 1u8).count();
+        ones <= 1
+    }
+    // convert to little ending bits and remove leading zeros if necessary
+    fn bits(&self, remove: bool) -> Vec<u8> {
+        self.polynomial().to_le_bits(remove)
+    }
+    // square root of binary field
+    // \sqrt(f(X)) = f_{even} + \sqrt(X) * f_{odd}, where \sqrt(X) is constant
+    fn sqrt(&self) -> Self {
+        let pair = self.polynomial().split();
+        Self::from_polynomial(pair[0]) + Self::reduce(pair[1] * Self::SQ)
+    }
+    // squaring of binary field
+    fn squaring(&self) -> Self {
+        #[cfg(not(feature = "constant-time"))]
+        {
+            if self.is_zero() {
+                return Self::zero();
+            }
+            if self.is_one() {
+                return Self::one();
+            }
+        }
+        Self::reduce(self.polynomial().squaring())
+    }
+    // x^{2^k}, which is linear over GF(2) and looked up by 4-bit chunks of x when k has a table,
+    // the constant-time backend always goes through k squarings instead
+    fn multi_square(&self, k: usize) -> Self {
+        #[cfg(not(feature = "constant-time"))]
+        if let Some((_, table)) = Self::multi_square_tables().iter().find(|(j, _)| *j == k) {
+            use polynomial::WORD_SIZE;
+            let x = self.polynomial();
+            let mut result = BinaryPolynomial::<N>::zero();
+            for j in 0..Self::M.div_ceil(4) {
+                let chunk = (x.0[4 * j / WORD_SIZE] >> (4 * j % WORD_SIZE)) & 0xf;
+                result = result + table[16 * j + chunk as usize].polynomial();
+            }
+            return Self::from_polynomial(result);
+        }
+        let mut result = *self;
+        for _ in 0..k {
+            result = result.squaring();
+        }
+        result
+    }
+    // trace of a binary field
+    // Tr(x) = x + x^2 + x^{2^2} + x^{2^3} + ... + x^{2^{M - 1}} \in {0, 1}, evaluated with the trace mask in constant time
+    fn trace(&self) -> u8 {
+        let ones = self
+            .polynomial()
+            .0
+            .iter()
+            .zip(Self::TR.0.iter())
+            .fold(0, |acc, (a, b)| acc ^ (a & b).count_ones());
+        (ones & 1) as u8
+    }
+    // half trace for odd M, H(c) = \sum_{i = 0}^{(M - 1) / 2} c^{2^{2i}}, which satisfies H(c)^2 + H(c) = c + Tr(c)
+    // Algorithm 3.85 in "Guide to Elliptic Curve Cryptography", even terms are folded with H(X^{2i}) = H(X^i) + X^i + Tr(X^i),
+    // and odd terms are looked up in the table
+    #[allow(clippy::needless_range_loop)]
+    fn half_trace(&self) -> Self {
+        assert!(Self::M % 2 == 1, "Half trace requires odd M!");
+        let table = Self::half_trace_table();
+        let mut c = self.polynomial();
+        let mut folded = BinaryPolynomial::<N>::zero();
+        let mut constant = 0u8;
+        for i in (1..=(Self::M - 1) / 2).rev() {
+            if c.get(2 * i) == 1u8 {
+                c.set(i, c.get(i) ^ 1u8);
+                folded.set(i, folded.get(i) ^ 1u8);
+                constant ^= Self::TR.get(i);
+            }
+        }
+        // H(1) = (M + 1) / 2 (mod 2)
+        constant ^= c.get(0) & (Self::M.div_ceil(2) % 2) as u8;
+        folded.set(0, constant);
+        let mut result = Self::from_polynomial(folded);
+        for i in 0..(Self::M - 1) / 2 {
+            if c.get(2 * i + 1) == 1u8 {
+                result = result + table[i];
+            }
+        }
+        result
+    }
+    // solve z^2 + z = c, which has solutions z and z + 1 if and only if Tr(c) = 0
+    fn solve_quadratic(c: Self) -> Option<Self> {
+        if c.trace() == 1u8 {
+            return None;
+        }
+        Some(c.half_trace())
+    }
+
+    // branch-free arithmetic for secret operands whatever the build is, i.e. no shortcuts on zero and one,
+    // no table lookups indexed by the operands and no degree check before the reduction
+    fn ct_add(&self, rhs: &Self) -> Self {
+        Self::from_polynomial(self.polynomial() + rhs.polynomial())
+    }
+    fn ct_mul(&self, rhs: &Self) -> Self {
+        Self::reduce_unchecked(self.polynomial().ct_mul(&rhs.polynomial()))
+    }
+    fn ct_squaring(&self) -> Self {
+        Self::reduce_unchecked(self.polynomial().masked_squaring())
+    }
+    // Itoh-Tsujii inversion over the branch-free operations, whose addition chain only depends on M,
+    // zero is mapped to zero
+    fn ct_inv(&self) -> Self {
+        let chain = itoh_tsujii_chain(Self::M);
+        let mut b = *self;
+        for a in chain.windows(2) {
+            // b_{2a} = b_a^{2^a} * b_a or b_{a + 1} = b_a^2 * x
+            let (k, rhs) = if a[1] == 2 * a[0] {
+                (a[0], b)
+            } else {
+                (1, *self)
+            };
+            for _ in 0..k {
+                b = b.ct_squaring();
+            }
+            b = b.ct_mul(&rhs);
+        }
+        b.ct_squaring()
+    }
+
+    // swap a and b if choice is 1 and keep them if choice is 0, without branching on choice
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: u8) {
+        let mask = (0 as WORD).wrapping_sub(choice as WORD);
+        let (mut pa, mut pb) = (a.polynomial(), b.polynomial());
+        for i in 0..N {
+            let t = mask & (pa.0[i] ^ pb.0[i]);
+            pa.0[i] ^= t;
+            pb.0[i] ^= t;
+        }
+        (*a, *b) = (Self::from_polynomial(pa), Self::from_polynomial(pb));
+        trace_op!("cswap");
+    }
+    // b if choice is 1 and a if choice is 0, without branching on choice
+    fn conditional_select(a: &Self, b: &Self, choice: u8) -> Self {
+        let mask = (0 as WORD).wrapping_sub(choice as WORD);
+        let (pa, pb) = (a.polynomial(), b.polynomial());
+        let mut result = pa;
+        for i in 0..N {
+            result.0[i] ^= mask & (pa.0[i] ^ pb.0[i]);
+        }
+        trace_op!("select");
+        Self::from_polynomial(result)
+    }
+    // 1 if self is zero and 0 otherwise, folding all words instead of comparing them one by one
+    fn ct_is_zero(&self) -> u8 {
+        trace_op!("is_zero");
+        let w = self.polynomial().0.iter().fold(0 as WORD, |acc, w| acc | w);
+        // the top bit of w | -w is set unless w = 0, and its lowest byte holds it after the shift whatever WORD is
+        1 ^ ((w | w.wrapping_neg()) >> (WORD::BITS - 1)).to_le_bytes()[0]
+    }
+    // big ending bytes with fixed length (M + 7) / 8
+    fn to_be_bytes(&self) -> Vec<u8> {
+        let bits = self.bits(false);
+        let mut result = vec![0u8; Self::M.div_ceil(8)];
+        let l = result.len();
+        for i in 0..Self::M {
+            result[l - 1 - i / 8] |= bits[i] << (i % 8);
+        }
+        result
+    }
+    // from big ending bytes with fixed length (M + 7) / 8, rejecting the ones with degree >= M
+    fn from_be_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let l = Self::M.div_ceil(8);
+        if bytes.len() != l {
+            return Err(Error::InvalidEncoding);
+        }
+        let bits = (0..(l * 8))
+            .map(|i| (bytes[l - 1 - i / 8] >> (i % 8)) & 1u8)
+            .collect::<Vec<_>>();
+        if bits[Self::M..].contains(&1u8) {
+            return Err(Error::DegreeTooLarge);
+        }
+        Ok(Self::from_polynomial(BinaryPolynomial::<N>::from_le_bits(
+            bits[..Self::M].to_vec(),
+        )))
+    }
+
+    // panicking counterpart of try_inv
+    fn inv(&self) -> Self {
+        self.try_inv().expect("Zero can not be inversed!")
+    }
+
+    // Montgomery's simultaneous inversion, i.e. 3(n - 1) multiplications and a single inversion for n elements,
+    // zeros have no inverse and are left as they are
+    fn batch_invert(elements: &mut [Self]) {
+        // prefix products of the nonzero elements
+        let mut prefix = Vec::with_capacity(elements.len());
+        let mut acc = Self::one();
+        for x in elements.iter() {
+            if !x.is_zero() {
+                acc = acc * *x;
+            }
+            prefix.push(acc);
+        }
+        let mut acc_inv = acc.inv();
+        for i in (0..elements.len()).rev() {
+            if elements[i].is_zero() {
+                continue;
+            }
+            // (x_0 ... x_i)^{-1} * (x_0 ... x_{i - 1}) = x_i^{-1}
+            let x = elements[i];
+            elements[i] = if i > 0 {
+                acc_inv * prefix[i - 1]
+            } else {
+                acc_inv
+            };
+            acc_inv = acc_inv * x;
+        }
+    }
+
+    // division which fails on zero denominator instead of panicking
+    fn checked_div(self, rhs: Self) -> Result<Self, Error> {
+        let rhs_inv = rhs.try_inv().map_err(|_| Error::DivisionByZero)?;
+        Ok(self * rhs_inv)
+    }
+
+    // Itoh-Tsujii inversion x^{-1} = (x^{2^{M - 1} - 1})^2, where b_a = x^{2^a - 1} walks through the addition chain of M - 1
+    // by b_{2a} = b_a^{2^a} * b_a and b_{a + 1} = b_a^2 * x, i.e. M - 1 squarings and about log2(M) multiplications
+    fn inv_itoh_tsujii(&self) -> Result<Self, Error> {
+        let chain = itoh_tsujii_chain(Self::M);
+        let mut b = *self;
+        for a in chain.windows(2) {
+            if a[1] == 2 * a[0] {
+                b = b.multi_square(a[0]) * b;
+            } else {
+                b = b.multi_square(1) * *self;
+            }
+        }
+        let result = b.multi_square(1);
+        // zero is the only element mapped to zero
+        if result.is_zero() {
+            return Err(Error::NotInvertible);
+        }
+        Ok(result)
+    }
+
+    // Itoh-Tsujii inversion, whose addition chain only depends on M
+    #[cfg(feature = "constant-time")]
+    fn try_inv(&self) -> Result<Self, Error> {
+        self.inv_itoh_tsujii()
+    }
+
+    // Algorithm 2.48 in "Guide to Elliptic Curve Cryptography"
+    // Euclidean based binary field inversion
+    #[cfg(not(feature = "constant-time"))]
+    fn try_inv(&self) -> Result<Self, Error> {
+        if self.is_zero() {
+            return Err(Error::NotInvertible);
+        }
+        if self.is_one() {
+            return Ok(Self::one());
+        }
+        let (mut u, mut v) = (
+            BinaryPolynomial2::<N>::from(self.polynomial()),
+            BinaryPolynomial2::<N>::from(Self::F),
+        );
+        let (mut g1, mut g2) = (Self::one(), Self::zero());
+        while !u.is_one() {
+            let mut j = u.degree() as i32 - v.degree() as i32;
+            if j < 0 {
+                u.swap(&mut v);
+                std::mem::swap(&mut g1, &mut g2);
+                j = -j;
+            }
+            u = u + (v << j as usize);
+            g1 = g1 + (g2 << j as usize);
+        }
+        Ok(g1)
+    }
+
+    // Modular composition of Brent and Kung, Algorithm 11.50 in "Handbook of Elliptic and HyperElliptic Curve Cryptography"
+    // compute f(X)^{2^r} (mod m(X)) = f(g(X)) (mod m(X)), where g(X) = X^{2^r}, deg(f) < M, deg(g) < M and deg(m) = M
+    #[allow(clippy::needless_range_loop)]
+    fn modular_composition(&self, g: Self) -> Self {
+        let k = (Self::M as f32).sqrt().ceil() as usize;
+        // precompute G_i[X] = 1, g, g^2, g^3, ...,g^{k - 1}
+        let mut G = vec![Self::zero(); k];
+        G[0] = Self::one();
+        for i in 1..k {
+            G[i] = g * G[i - 1];
+        }
+        let Gk = g * G[k - 1];
+        // precompute P_i[X] = 1, g^k, g^{2k}, g^{3k}, ..., g^{(k - 1)k}
+        let mut P = vec![Self::zero(); k];
+        P[0] = Self::one();
+        for i in 1..k {
+            P[i] = Gk * P[i - 1];
+        }
+        // compute F_i(X) = \sum_{j = 0}^{k - 1} f_{i * k + j} G_j[X]
+        let f = self.polynomial();
+        let mut F = vec![Self::zero(); k];
+        for i in 0..k {
+            for j in 0..k {
+                if i * k + j >= Self::M {
+                    continue;
+                }
+                if f.get(i * k + j) == 1u8 {
+                    F[i] = F[i] + G[j];
+                }
+            }
+        }
+        // compute R = \sum_{i = 0}^{k - 1} F_i[X] * P_i[X] (mod m(X))
+        let mut R = Self::zero();
+        for i in 0..k {
+            R = R + F[i] * P[i];
+        }
+        R
+    }
+
+    // Shoup exponentiation algorithm for binary field, algorithm 11.53 in "Handbook of Elliptic and HyperElliptic Curve Cryptography"
+    // compute f(X)^{n(X)} (mod m(X)) = f(X)^{n_0(X) + n_1(X) * t(X) + n_2(X) * t(X)^2 + ... + n_{l - 1}(X) * t(X)^{l - 1}}
+    #[allow(clippy::needless_range_loop)]
+    fn exp(&self, e: BinaryPolynomial<N>) -> Self {
+        assert!(e.degree() < Self::M, "Input parameter n is too big!");
+        let r = (Self::M as f64 / (Self::M as f64).log2()).ceil() as usize;
+        let n = e.chunks(r);
+        let l = n.len();
+        // precompute f^{ni}(X) (mod m(X))
+        let mut f_pow_2 = vec![Self::zero(); r];
+        f_pow_2[0] = *self;
+        for i in 1..r {
+            f_pow_2[i] = f_pow_2[i - 1].squaring();
+        }
+        let mut f_n = vec![Self::one(); l];
+        for i in 0..n.len() {
+            for j in 0..r {
+                let mask = 1 << j;
+                if n[i] & mask == mask {
+                    f_n[i] = f_n[i] * f_pow_2[j];
+                }
+            }
+        }
+        // compute g(X) = X^{2^r} (mod m(X))
+        let mut g = Self::one() << 1;
+        for _ in 1..(r + 1) {
+            g = g.squaring();
+        }
+        // f^n = f^{n_0 + t * n_1 + t^2 * n_2 + ... + t^{l - 1} * n_{l - 1}}
+        let mut y = Self::one();
+        for i in (0..l).rev() {
+            y = y.modular_composition(g);
+            y = y * f_n[i];
+        }
+        y
+    }
+}
+
+// H(X^i) for odd i < M evaluated by definition, i.e. the lookup table of BinaryField::half_trace
+#[doc(hidden)]
+pub fn half_trace_table<const N: usize, Field: BinaryField<N>>() -> Vec<Field> {
+    (0..(Field::M - 1) / 2)
+        .map(|i| {
+            let mut sq = Field::one() << (2 * i + 1);
+            let mut result = sq;
+            for _ in 0..(Field::M - 1) / 2 {
+                sq = sq.squaring().squaring();
+                result = result + sq;
+            }
+            result
+        })
+        .collect()
+}
+
+// addition chain of M - 1 by its binary expansion from the top bit, doubling a at every bit and adding 1 at the bits of one,
+// e.g. 1, 2, 3, 6, 7, 14, 28, 29, 58, 116, 232 for M = 233
+pub fn itoh_tsujii_chain(m: usize) -> Vec<usize> {
+    let e = m - 1;
+    let mut chain = vec![1];
+    for i in (0..(usize::BITS - 1 - e.leading_zeros())).rev() {
+        let a = chain[chain.len() - 1];
+        chain.push(2 * a);
+        if (e >> i) & 1 == 1 {
+            chain.push(2 * a + 1);
+        }
+    }
+    chain
+}
+
+// x^{2^k} tables for the multi-squarings in the addition chain of Itoh-Tsujii inversion,
+// where entry 16j + v is (v * X^{4j})^{2^k} and the images of X^i are (X^{2^k})^i,
+// every k gets a table since the lookup beats k squarings from k = 1 on in the multi_square benchmark
+#[doc(hidden)]
+pub fn multi_square_tables<const N: usize, Field: BinaryField<N>>() -> Vec<(usize, Vec<Field>)> {
+    itoh_tsujii_chain(Field::M)
+        .windows(2)
+        .filter(|a| a[1] == 2 * a[0])
+        .map(|a| {
+            let k = a[0];
+            let mut x_k = Field::one() << 1;
+            for _ in 0..k {
+                x_k = x_k.squaring();
+            }
+            let mut basis = vec![Field::one(); Field::M];
+            for i in 1..Field::M {
+                basis[i] = basis[i - 1] * x_k;
+            }
+            let table = (0..16 * Field::M.div_ceil(4))
+                .map(|e| {
+                    let (j, v) = (e / 16, e % 16);
+                    (0..4)
+                        .filter(|b| (v >> b) & 1 == 1 && 4 * j + b < Field::M)
+                        .fold(Field::zero(), |acc, b| acc + basis[4 * j + b])
+                })
+                .collect();
+            (k, table)
+        })
+        .collect()
+}
+
+// implement hex conversions, bit accessors and arithmetic operators for a binary field
+// which is a newtype over BinaryPolynomial<N> and implements BinaryField<N>
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_binary_field_ops {
+    ($Field:ident, $N:expr) => {
+        #[allow(dead_code)]
+        impl $Field {
+            // convert to hex string
+            pub fn to_hex_string(&self) -> String {
+                self.0.to_hex_string()
+            }
+
+            // initiate from hex string, panics on malformed input
+            pub fn from_hex_string(s: &str) -> Self {
+                Self::try_from_hex_string(s).expect("Invalid hex string")
+            }
+
+            // initiate from hex string, which must start with "0x" and represent a polynomial of degree < 2M - 1
+            pub fn try_from_hex_string(s: &str) -> Result<Self, $crate::error::Error> {
+                use $crate::binary_field::polynomial::{
+                    BinaryPolynomial, BinaryPolynomial2, WORD_SIZE,
+                };
+                use $crate::error::Error;
+                let hex_string = s.strip_prefix("0x").ok_or(Error::InvalidHexString)?;
+                if hex_string.is_empty() || !hex_string.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return Err(Error::InvalidHexString);
+                }
+                // reduce() accepts binary polynomials with degree up to 2M - 2
+                let digits = hex_string.trim_start_matches('0');
+                if let Some(leading) = digits.chars().next() {
+                    let leading_bits = 32 - leading.to_digit(16).unwrap().leading_zeros() as usize;
+                    if (digits.len() - 1) * 4 + leading_bits > 2 * <Self as BinaryField<$N>>::M - 1
+                    {
+                        return Err(Error::DegreeTooLarge);
+                    }
+                }
+                let digits = if digits.len() % 2 == 1 {
+                    format!("0{}", digits)
+                } else {
+                    digits.to_string()
+                };
+                let mut result = BinaryPolynomial2::<$N>::zero();
+                let hex_number_boundary = $N * WORD_SIZE / 4;
+                if digits.len() > hex_number_boundary {
+                    let boundary = digits.len() - hex_number_boundary;
+                    result.0[0] = BinaryPolynomial::<$N>::from_hex_string(&format!(
+                        "0x{}",
+                        &digits[boundary..]
+                    ));
+                    result.0[1] = BinaryPolynomial::<$N>::from_hex_string(&format!(
+                        "0x{}",
+                        &digits[..boundary]
+                    ));
+                } else if !digits.is_empty() {
+                    result.0[0] = BinaryPolynomial::<$N>::from_hex_string(&format!("0x{}", digits));
+                }
+                Ok(Self::reduce(result))
+            }
+
+            // get bit value of binary field
+            pub fn get(&self, offset: usize) -> u8 {
+                assert!(offset < <Self as BinaryField<$N>>::M, "offset is too big!");
+                self.0.get(offset)
+            }
+
+            // set bit value of binary field
+            pub fn set(&mut self, offset: usize, bit: u8) {
+                assert!(offset < <Self as BinaryField<$N>>::M, "offset is too big!");
+                self.0.set(offset, bit);
+            }
+
+            // swap two binary field
+            pub fn swap(&mut self, other: &mut Self) {
+                std::mem::swap(self, other);
+            }
+
+            // degree of the underlying polynomial, always less than M since elements are kept reduced
+            pub fn degree(&self) -> usize {
+                self.0.degree()
+            }
+        }
+
+        impl TryFrom<&str> for $Field {
+            type Error = $crate::error::Error;
+
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                Self::try_from_hex_string(s)
+            }
+        }
+
+        impl core::ops::Div for $Field {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                self.checked_div(rhs)
+                    .expect("Denominator should not be zero!")
+            }
+        }
+
+        impl core::ops::Add for $Field {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                if !$crate::binary_field::CONSTANT_TIME {
+                    if self == Self::zero() {
+                        return rhs;
+                    }
+                    if rhs == Self::zero() {
+                        return self;
+                    }
+                }
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl core::ops::Shl<usize> for $Field {
+            type Output = Self;
+
+            fn shl(self, shift: usize) -> Self::Output {
+                Self::reduce(
+                    $crate::binary_field::polynomial::BinaryPolynomial2::<$N>::from(self.0)
+                        << shift,
+                )
+            }
+        }
+
+        impl core::ops::Shr<usize> for $Field {
+            type Output = Self;
+
+            fn shr(self, shift: usize) -> Self::Output {
+                Self::reduce(
+                    $crate::binary_field::polynomial::BinaryPolynomial2::<$N>::from(self.0)
+                        >> shift,
+                )
+            }
+        }
+
+        impl core::ops::Mul for $Field {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                if !$crate::binary_field::CONSTANT_TIME {
+                    if (self == Self::zero()) || (rhs == Self::zero()) {
+                        return Self::zero();
+                    }
+                    if self == Self::one() {
+                        return rhs;
+                    }
+                    if rhs == Self::one() {
+                        return self;
+                    }
+                    if self == rhs {
+                        return self.squaring();
+                    }
+                }
+                Self::reduce(self.0 * rhs.0)
+            }
+        }
+
+        impl core::ops::Neg for $Field {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self(-self.0)
+            }
+        }
+
+        impl core::ops::Sub<Self> for $Field {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                if !$crate::binary_field::CONSTANT_TIME {
+                    if self == Self::zero() {
+                        return -rhs;
+                    }
+                    if rhs == Self::zero() {
+                        return self;
+                    }
+                }
+                Self(self.0 - rhs.0)
+            }
+        }
+    };
+}
+/// Define the binary field GF(2^m) = GF(2)[X] / f(X) for a trinomial f(X) = X^m + X^k + 1 with `[k]`, or a pentanomial
+/// f(X) = X^m + X^k1 + X^k2 + X^k3 + 1 with `[k1, k2, k3]`, together with the constants M and N of the invoking module,
+/// the word-level reduction, \sqrt(X) and the trace mask derived at compile time, and tests of the reduction against
+/// long division, so the invoking module only imports `BinaryField` and `define_binary_field`, from this crate or any
+/// other one since the generated code follows the features of this crate, see `define_binary_curve!` for an example
+#[macro_export]
+macro_rules! define_binary_field {
+    ($Field:ident, $m:expr, [$k:expr]) => {
+        $crate::define_binary_field!(@field $Field, $m, [$k, 0]);
+    };
+    ($Field:ident, $m:expr, [$k1:expr, $k2:expr, $k3:expr]) => {
+        $crate::define_binary_field!(@field $Field, $m, [$k1, $k2, $k3, 0]);
+    };
+    (@field $Field:ident, $m:expr, [$($r:expr),+]) => {
+        pub const M: usize = $m;
+        pub const N: usize = M.div_ceil($crate::binary_field::polynomial::WORD_SIZE);
+        // r(X) = f(X) - X^m by its exponents, in descending order
+        pub const R: &[usize] = &[$($r),+];
+        const _: () = {
+            let mut i = 1;
+            while i < R.len() {
+                assert!(R[i - 1] > R[i], "Exponents must be in descending order.");
+                i += 1;
+            }
+            // a word never folds into itself during the word-level reduction
+            assert!(
+                R[0] + $crate::binary_field::polynomial::WORD_SIZE <= M,
+                "Middle exponents are too close to m for the word size."
+            );
+        };
+
+        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        pub struct $Field(pub $crate::binary_field::polynomial::BinaryPolynomial<N>);
+
+        $crate::impl_binary_field_ops!($Field, N);
+
+        impl $crate::binary_field::BinaryField<N> for $Field {
+            const M: usize = M;
+            const F: $crate::binary_field::polynomial::BinaryPolynomial<N> =
+                $crate::binary_field::polynomial::BinaryPolynomial::from_exponents(&[M, $($r),+]);
+            const TR: $crate::binary_field::polynomial::BinaryPolynomial<N> =
+                $crate::binary_field::polynomial::BinaryPolynomial::trace_mask(M, R);
+            const SQ: $crate::binary_field::polynomial::BinaryPolynomial<N> =
+                $crate::binary_field::polynomial::BinaryPolynomial::sqrt_x(M, R);
+
+            fn reduce(ele: $crate::binary_field::polynomial::BinaryPolynomial2<N>) -> Self {
+                // the degree check scans the words from the top, which the constant-time backend can not afford
+                if !$crate::binary_field::CONSTANT_TIME {
+                    assert!(
+                        ele.degree() <= 2 * M - 2,
+                        "Degree of binary polynomial is too big."
+                    );
+                }
+                Self::reduce_unchecked(ele)
+            }
+
+            fn reduce_unchecked(
+                ele: $crate::binary_field::polynomial::BinaryPolynomial2<N>,
+            ) -> Self {
+                $crate::binary_field::record_op("reduce");
+                Self(ele.reduce_sparse(M, R))
+            }
+
+            fn from_polynomial(v: $crate::binary_field::polynomial::BinaryPolynomial<N>) -> Self {
+                Self(v)
+            }
+
+            fn polynomial(&self) -> $crate::binary_field::polynomial::BinaryPolynomial<N> {
+                self.0
+            }
+
+            fn half_trace_table() -> &'static [Self] {
+                static TABLE: std::sync::OnceLock<Vec<$Field>> = std::sync::OnceLock::new();
+                TABLE.get_or_init($crate::binary_field::half_trace_table::<N, Self>)
+            }
+
+            fn multi_square_tables() -> &'static [(usize, Vec<Self>)] {
+                static TABLES: std::sync::OnceLock<Vec<(usize, Vec<$Field>)>> =
+                    std::sync::OnceLock::new();
+                TABLES.get_or_init($crate::binary_field::multi_square_tables::<N, Self>)
+            }
+        }
+
+        #[cfg(test)]
+        mod field_tests {
+            use super::*;
+            use $crate::binary_field::polynomial::{
+                BinaryPolynomial, BinaryPolynomial2, WORD, WORD_SIZE,
+            };
+            use $crate::binary_field::rand::{self, Rng};
+
+            #[test]
+            fn test_reduce_long_division() {
+                let mut rng = rand::rng();
+                let mut random = || {
+                    let v = BinaryPolynomial::<N>(std::array::from_fn(|_| rng.random::<WORD>()));
+                    <$Field>::reduce(v.into()).0
+                };
+                // all the bits of degree up to 2M - 2 set, the top bit alone, f(X) itself and random products
+                let (mut ones, mut top) = (BinaryPolynomial2::<N>::zero(), BinaryPolynomial2::<N>::zero());
+                for i in 0..2 * M - 1 {
+                    ones[i / WORD_SIZE] |= 1 << (i % WORD_SIZE);
+                }
+                top[(2 * M - 2) / WORD_SIZE] |= 1 << ((2 * M - 2) % WORD_SIZE);
+                let mut inputs = vec![ones, top, BinaryPolynomial2::from(<$Field>::F)];
+                inputs.extend((0..32).map(|_| random().window_mul(&random())));
+                for c in inputs {
+                    assert_eq!(
+                        <$Field>::reduce(c).0,
+                        c.long_division(M, R),
+                        "Test for word-level reduction failed!"
+                    );
+                }
+            }
+
+            #[test]
+            fn test_derived_constants() {
+                // \sqrt(X)^2 = X
+                let sq = <$Field>::from_polynomial(<$Field>::SQ);
+                assert_eq!(sq.squaring(), <$Field>::one() << 1);
+                // Tr(X^i) = \sum_{j < M} X^{i 2^j} for a few powers of X
+                for i in [0, 1, 2, M / 2, M - 1] {
+                    let x = <$Field>::one() << i;
+                    let (mut t, mut tr) = (x, x);
+                    for _ in 1..M {
+                        t = t.squaring();
+                        tr = tr + t;
+                    }
+                    let expected = if tr.is_one() { 1 } else { 0 };
+                    assert!(tr.is_zero() || tr.is_one());
+                    assert_eq!(<$Field>::TR.get(i), expected, "Test for trace mask failed!");
+                }
+            }
+        }
+    };
 }
+// record a primitive operation for the tests counting or comparing operations, and nothing otherwise
+macro_rules! trace_op {
+    ($op:expr) => {
+        #[cfg(test)]
+        $crate::binary_field::op_trace::record($op);
+    };
+}
+pub(crate) use trace_op;
+
+// trace_op! for the code generated by define_binary_field!, whose cfg(test) would be the one of the invoking crate
+#[doc(hidden)]
+#[cfg_attr(not(test), allow(unused_variables))]
+pub fn record_op(op: &'static str) {
+    trace_op!(op);
+}
+
+// whether the constant-time feature is on, read by the generated code instead of cfg(feature) for the same reason
+#[doc(hidden)]
+pub const CONSTANT_TIME: bool = cfg!(feature = "constant-time");
+
+#[doc(hidden)]
+pub use rand;
 
 #[allow(dead_code)]
 pub trait BinaryWord: Sized {
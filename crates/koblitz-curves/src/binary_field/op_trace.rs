@@ -0,0 +1,29 @@
+/// Trace of primitive field operations, compiled only for tests such as the ones of the constant-time backend,
+/// it records the sequence of operations (add, mul, sqr, reduce, cswap, select and is_zero) rather than the
+/// instructions inside them, so equal traces rule out early returns and secret-dependent operation counts only
+use std::cell::RefCell;
+
+thread_local! {
+    static TRACE: RefCell<Option<Vec<&'static str>>> = const { RefCell::new(None) };
+}
+
+// append one operation to the trace of current thread, if it is being traced
+pub(crate) fn record(op: &'static str) {
+    TRACE.with(|trace| {
+        if let Some(trace) = trace.borrow_mut().as_mut() {
+            trace.push(op);
+        }
+    });
+}
+
+// run f from an empty trace and return the operations it went through
+pub(crate) fn trace<R>(f: impl FnOnce() -> R) -> Vec<&'static str> {
+    TRACE.with(|trace| *trace.borrow_mut() = Some(Vec::new()));
+    std::hint::black_box(f());
+    TRACE.with(|trace| trace.take()).unwrap_or_default()
+}
+
+// number of times op shows up in the trace of f
+pub(crate) fn count<R>(op: &'static str, f: impl FnOnce() -> R) -> usize {
+    trace(f).iter().filter(|o| **o == op).count()
+}
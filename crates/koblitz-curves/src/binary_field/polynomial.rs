@@ -1,16 +1,28 @@
 #![allow(dead_code)]
 use super::BinaryWord;
+use super::clmul;
+use super::trace_op;
 use super::word::*;
 use hex;
+use num_bigint::BigUint;
 use std::fmt::Debug;
 use std::ops::{Add, Mul, Neg, Shl, Shr, Sub};
 use std::ops::{Index, IndexMut};
 
-// u8 word only for testing purpose, actually we will use u32 or u64 as one word
+// the word type is selected at compile time, u32 by default, u64 with feature `word-u64` for 64-bit hosts
+// and u8 with feature `word-u8` for constrained targets, which takes precedence so that features stay additive
+#[cfg(feature = "word-u8")]
+pub type WORD = WORD8;
+#[cfg(all(feature = "word-u64", not(feature = "word-u8")))]
+pub type WORD = WORD64;
+#[cfg(not(any(feature = "word-u8", feature = "word-u64")))]
 pub type WORD = WORD32;
-pub const WORD_SIZE: usize = 32;
+pub const WORD_SIZE: usize = WORD::BITS as usize;
 // window size for caching when doing bigint multiplication
 pub const WINDOW_SIZE: usize = 4;
+const _: () = assert!(WORD_SIZE % WINDOW_SIZE == 0);
+// Karatsuba multiplication falls back to schoolbook multiplication on at most this number of words
+pub const KARATSUBA_THRESHOLD: usize = 6;
 
 // binary polynomial representation for bigint
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -22,12 +34,12 @@ impl<const N: usize> BinaryPolynomial<N> {
     // this is for the purpose of sqrt
     pub fn split(&self) -> [BinaryPolynomial<N>; 2] {
         let bit_vec = self.to_le_bits(false);
-        let even = bit_vec.iter().step_by(2).map(|b| *b).collect::<Vec<_>>();
+        let even = bit_vec.iter().step_by(2).copied().collect::<Vec<_>>();
         let odd = bit_vec
             .iter()
             .skip(1)
             .step_by(2)
-            .map(|b| *b)
+            .copied()
             .collect::<Vec<_>>();
         [
             BinaryPolynomial::from_le_bits(even),
@@ -122,7 +134,7 @@ impl<const N: usize> BinaryPolynomial<N> {
     }
 
     // from big ending hex bytes string
-    pub fn from_hex_string(s: &String) -> Self {
+    pub fn from_hex_string(s: &str) -> Self {
         assert!(s.starts_with("0x"));
         let chunk_size = WORD_SIZE / 8;
 
@@ -161,6 +173,7 @@ impl<const N: usize> BinaryPolynomial<N> {
     }
 
     // from bit vec with big ending
+    #[allow(clippy::needless_range_loop)]
     pub fn from_le_bits(bit_vec: Vec<u8>) -> Self {
         let n = bit_vec.len();
         assert!(n <= N * WORD_SIZE);
@@ -184,12 +197,11 @@ impl<const N: usize> BinaryPolynomial<N> {
     }
 
     // from bit string with big ending
-    pub fn from_bit_string(s: &String) -> Self {
+    pub fn from_bit_string(s: &str) -> Self {
         assert!(s.starts_with("0b"));
         let bit_vec = s
             .chars()
             .skip(2)
-            .into_iter()
             .map(|c| if c == '1' { 1u8 } else { 0u8 })
             .collect::<Vec<_>>();
         Self::from_le_bits(bit_vec.into_iter().rev().collect::<Vec<_>>())
@@ -200,7 +212,7 @@ impl<const N: usize> BinaryPolynomial<N> {
         if self.is_zero() {
             return 0;
         }
-        let mut n = 0 as usize;
+        let mut n = 0;
         for i in (0..N).rev() {
             let zeros = self.0[i].leading_zeros() as usize;
             if (n % WORD_SIZE == 0) && (zeros < WORD_SIZE) {
@@ -222,6 +234,135 @@ impl<const N: usize> BinaryPolynomial<N> {
         result
     }
 
+    // \sum_i X^{e_i}, for constants that do not depend on the word size
+    pub const fn from_exponents(exponents: &[usize]) -> Self {
+        let mut words = [0 as WORD; N];
+        let mut i = 0;
+        while i < exponents.len() {
+            let e = exponents[i];
+            assert!(e < N * WORD_SIZE, "Exponent is out of range.");
+            words[e / WORD_SIZE] ^= 1 << (e % WORD_SIZE);
+            i += 1;
+        }
+        Self(words)
+    }
+
+    // repack little ending u32 words into words of the selected size, for constants that do not depend on the word size
+    pub const fn from_u32_words(v: &[u32]) -> Self {
+        let mut words = [0 as WORD; N];
+        let mut i = 0;
+        while i < v.len() * 32 {
+            if (v[i / 32] >> (i % 32)) & 1 == 1 {
+                assert!(i < N * WORD_SIZE, "Constant is out of range.");
+                words[i / WORD_SIZE] |= 1 << (i % WORD_SIZE);
+            }
+            i += 1;
+        }
+        Self(words)
+    }
+
+    // squaring modulo f(X) = X^m + \sum_{r \in R} X^r bit by bit, only meant for deriving constants at compile time
+    pub const fn square_sparse(&self, m: usize, R: &[usize]) -> Self {
+        let mut C = [[0 as WORD; N]; 2];
+        let mut i = 0;
+        while i < N * WORD_SIZE {
+            if (self.0[i / WORD_SIZE] >> (i % WORD_SIZE)) & 1 == 1 {
+                let j = 2 * i / WORD_SIZE;
+                C[j / N][j % N] |= 1 << (2 * i % WORD_SIZE);
+            }
+            i += 1;
+        }
+        BinaryPolynomial2([BinaryPolynomial(C[0]), BinaryPolynomial(C[1])]).reduce_sparse(m, R)
+    }
+
+    // multiplication modulo f(X) = X^m + \sum_{r \in R} X^r bit by bit, only meant for checking constants at compile time
+    pub const fn mul_sparse(&self, rhs: &Self, m: usize, R: &[usize]) -> Self {
+        let mut C = [[0 as WORD; N]; 2];
+        let mut i = 0;
+        while i < N * WORD_SIZE {
+            if (self.0[i / WORD_SIZE] >> (i % WORD_SIZE)) & 1 == 1 {
+                let (k, s) = (i / WORD_SIZE, i % WORD_SIZE);
+                let mut j = 0;
+                while j < N {
+                    let (w, p) = (rhs.0[j], k + j);
+                    C[p / N][p % N] ^= w << s;
+                    if s > 0 {
+                        C[(p + 1) / N][(p + 1) % N] ^= w >> (WORD_SIZE - s);
+                    }
+                    j += 1;
+                }
+            }
+            i += 1;
+        }
+        BinaryPolynomial2([BinaryPolynomial(C[0]), BinaryPolynomial(C[1])]).reduce_sparse(m, R)
+    }
+
+    // \sqrt(x) = x^{2^{m - 1}} (mod f(X)), where f(X) = X^m + \sum_{r \in R} X^r and deg(x) < m
+    pub const fn sqrt_sparse(&self, m: usize, R: &[usize]) -> Self {
+        let mut x = *self;
+        let mut i = 1;
+        while i < m {
+            x = x.square_sparse(m, R);
+            i += 1;
+        }
+        x
+    }
+
+    // \sqrt(X) = X^{2^{m - 1}} (mod f(X)), where f(X) = X^m + \sum_{r \in R} X^r
+    pub const fn sqrt_x(m: usize, R: &[usize]) -> Self {
+        Self::from_exponents(&[1]).sqrt_sparse(m, R)
+    }
+
+    // the mask whose i-th bit is Tr(X^i), i.e. the power sum p_i of the roots of f(X) = X^m + \sum_{r \in R} X^r,
+    // by Newton's identities p_i = \sum_{0 < j < i} a_j p_{i - j} + i a_i where f(X) = X^m + \sum_j a_j X^{m - j}
+    pub const fn trace_mask(m: usize, R: &[usize]) -> Self {
+        let mut words = [0 as WORD; N];
+        // Tr(1) = m (mod 2)
+        words[0] = (m % 2) as WORD;
+        let mut i = 1;
+        while i < m {
+            let mut p = 0 as WORD;
+            let mut k = 0;
+            while k < R.len() {
+                let j = m - R[k];
+                if j < i {
+                    p ^= (words[(i - j) / WORD_SIZE] >> ((i - j) % WORD_SIZE)) & 1;
+                } else if j == i {
+                    p ^= (i % 2) as WORD;
+                }
+                k += 1;
+            }
+            words[i / WORD_SIZE] |= p << (i % WORD_SIZE);
+            i += 1;
+        }
+        Self(words)
+    }
+
+    // const parser of big ending hex strings starting with "0x", for constants written in hex
+    pub const fn from_hex_str(s: &str) -> Self {
+        let bytes = s.as_bytes();
+        assert!(
+            bytes.len() > 2 && bytes[0] == b'0' && bytes[1] == b'x',
+            "Invalid hex string"
+        );
+        let mut words = [0 as WORD; N];
+        let mut i = 0;
+        while i < bytes.len() - 2 {
+            let digit = match bytes[bytes.len() - 1 - i] {
+                c @ b'0'..=b'9' => c - b'0',
+                c @ b'a'..=b'f' => c - b'a' + 10,
+                c @ b'A'..=b'F' => c - b'A' + 10,
+                _ => panic!("Invalid hex string"),
+            } as WORD;
+            if digit != 0 {
+                assert!(4 * i < N * WORD_SIZE, "Constant is out of range.");
+                words[4 * i / WORD_SIZE] |= digit << (4 * i % WORD_SIZE);
+            }
+            i += 1;
+        }
+        Self(words)
+    }
+
     pub fn is_zero(&self) -> bool {
         *self == Self::zero()
     }
@@ -230,8 +371,42 @@ impl<const N: usize> BinaryPolynomial<N> {
         *self == Self::one()
     }
 
+    // the constant-time backend squares with shifts and masks
+    #[cfg(feature = "constant-time")]
+    pub fn squaring(&self) -> BinaryPolynomial2<N> {
+        self.masked_squaring()
+    }
+
+    // squaring by spreading the bits of each word with fixed shifts and masks, instead of table lookups indexed by the bytes
+    pub fn masked_squaring(&self) -> BinaryPolynomial2<N> {
+        trace_op!("sqr");
+        const MASKS: [u128; 6] = [
+            0x0000_0000_ffff_ffff_0000_0000_ffff_ffff,
+            0x0000_ffff_0000_ffff_0000_ffff_0000_ffff,
+            0x00ff_00ff_00ff_00ff_00ff_00ff_00ff_00ff,
+            0x0f0f_0f0f_0f0f_0f0f_0f0f_0f0f_0f0f_0f0f,
+            0x3333_3333_3333_3333_3333_3333_3333_3333,
+            0x5555_5555_5555_5555_5555_5555_5555_5555,
+        ];
+        let mut result = BinaryPolynomial2::<N>::zero();
+        for i in 0..N {
+            let mut w = self.0[i] as u128;
+            for (j, mask) in MASKS.iter().enumerate() {
+                let shift = 32 >> j;
+                if shift < WORD_SIZE {
+                    w = (w | (w << shift)) & mask;
+                }
+            }
+            result[2 * i] = w as WORD;
+            result[2 * i + 1] = (w >> WORD_SIZE) as WORD;
+        }
+        result
+    }
+
     // Algorithm 2.39 in "Gude to Elliptic Curve Cryptography"
+    #[cfg(not(feature = "constant-time"))]
     pub fn squaring(&self) -> BinaryPolynomial2<N> {
+        trace_op!("sqr");
         let mut result = Vec::with_capacity(2 * N);
         // precomputation for byte squaring
         let capacity = 1 << 8;
@@ -309,7 +484,9 @@ impl<const N: usize> From<Vec<WORD>> for BinaryPolynomial<N> {
 impl<const N: usize> Add for BinaryPolynomial<N> {
     type Output = Self;
 
+    #[allow(clippy::needless_range_loop, clippy::suspicious_arithmetic_impl)]
     fn add(self, rhs: Self) -> Self::Output {
+        trace_op!("add");
         let mut result = [0 as WORD; N];
         for i in 0..N {
             result[i] = self.0[i] ^ rhs.0[i];
@@ -341,21 +518,18 @@ impl<const N: usize> Mul<WORD> for BinaryPolynomial<N> {
     }
 }
 
-// Algorithm 11.37 in "Handbook of Elliptic and HyperElliptic Curve Cryptography"
-// This is a window-based optimized algorithm of binary polynomial multiplication,
-impl<const N: usize> Mul for BinaryPolynomial<N> {
-    type Output = BinaryPolynomial2<N>;
-
-    fn mul(self, rhs: Self) -> Self::Output {
+impl<const N: usize> BinaryPolynomial<N> {
+    // Algorithm 11.37 in "Handbook of Elliptic and HyperElliptic Curve Cryptography"
+    // This is a window-based optimized algorithm of binary polynomial multiplication
+    pub fn window_mul(&self, rhs: &Self) -> BinaryPolynomial2<N> {
         // cache lookup table
-        assert!(WORD_SIZE % WINDOW_SIZE == 0);
         let capacity = 1 << WINDOW_SIZE;
         let mut lookup_table = vec![BinaryPolynomial2::<N>::zero(); capacity];
         for i in 1..capacity {
             if i % 2 == 0 {
                 lookup_table[i] = lookup_table[i / 2] << 1;
             } else {
-                lookup_table[i] = lookup_table[i - 1] + rhs;
+                lookup_table[i] = lookup_table[i - 1] + *rhs;
             }
         }
         // iterate by window
@@ -371,6 +545,124 @@ impl<const N: usize> Mul for BinaryPolynomial<N> {
         }
         c
     }
+
+    // Karatsuba-Ofman multiplication, which splits both operands in lower and higher halves
+    // until at most threshold words are left
+    pub fn karatsuba_mul(&self, rhs: &Self, threshold: usize) -> BinaryPolynomial2<N> {
+        let mut c = vec![0 as WORD; 2 * N];
+        karatsuba(&self.0, &rhs.0, threshold.max(1), &mut c);
+        BinaryPolynomial2::from(c)
+    }
+
+    // PCLMULQDQ when the host has it and the masked multiplication otherwise, neither of which branches on or looks up
+    // tables by the bits of the operands
+    pub fn ct_mul(&self, rhs: &Self) -> BinaryPolynomial2<N> {
+        trace_op!("mul");
+        clmul::mul(self, rhs).unwrap_or_else(|| self.masked_mul(rhs))
+    }
+
+    // multiplication going bit by bit, which adds rhs under a mask instead of a table lookup indexed by the bits
+    #[allow(clippy::needless_range_loop)]
+    pub fn masked_mul(&self, rhs: &Self) -> BinaryPolynomial2<N> {
+        // rhs * X^{i * WORD_SIZE}
+        let shifted = (0..N)
+            .map(|i| BinaryPolynomial2::<N>::from(*rhs) << (i * WORD_SIZE))
+            .collect::<Vec<_>>();
+        let mut c = BinaryPolynomial2::<N>::zero();
+        for j in (0..WORD_SIZE).rev() {
+            for i in 0..N {
+                let mask = (0 as WORD).wrapping_sub((self.0[i] >> j) & 1);
+                for k in 0..2 * N {
+                    c[k] ^= shifted[i][k] & mask;
+                }
+            }
+            if j != 0 {
+                c = c << 1;
+            }
+        }
+        c
+    }
+}
+
+// carry-less product of two words with a window over a, returned as the lower and higher words
+fn word_mul(a: WORD, b: WORD) -> [WORD; 2] {
+    let mut lookup_table = [0u128; 1 << WINDOW_SIZE];
+    for i in 1..lookup_table.len() {
+        if i % 2 == 0 {
+            lookup_table[i] = lookup_table[i / 2] << 1;
+        } else {
+            lookup_table[i] = lookup_table[i - 1] ^ b as u128;
+        }
+    }
+    let mut c = 0u128;
+    for j in (0..(WORD_SIZE / WINDOW_SIZE)).rev() {
+        let chunk_word = (a >> (j * WINDOW_SIZE)) & ((lookup_table.len() - 1) as WORD);
+        c = (c << WINDOW_SIZE) ^ lookup_table[chunk_word as usize];
+    }
+    [c as WORD, (c >> WORD_SIZE) as WORD]
+}
+
+// c += a * b, where a and b have the same number of words and c has twice of them
+// a * b = a_0 b_0 + ((a_0 + a_1)(b_0 + b_1) + a_0 b_0 + a_1 b_1) X^{h * WORD_SIZE} + a_1 b_1 X^{2h * WORD_SIZE}
+fn karatsuba(a: &[WORD], b: &[WORD], threshold: usize, c: &mut [WORD]) {
+    let n = a.len();
+    if n <= threshold {
+        for i in 0..n {
+            for j in 0..n {
+                let [low, high] = word_mul(a[i], b[j]);
+                c[i + j] ^= low;
+                c[i + j + 1] ^= high;
+            }
+        }
+        return;
+    }
+    // lower halves have h words and higher halves have t >= h words
+    let (h, t) = (n / 2, n - n / 2);
+    let ((a0, a1), (b0, b1)) = (a.split_at(h), b.split_at(h));
+    let (mut z0, mut z1, mut z2) = (
+        vec![0 as WORD; 2 * h],
+        vec![0 as WORD; 2 * t],
+        vec![0 as WORD; 2 * t],
+    );
+    karatsuba(a0, b0, threshold, &mut z0);
+    karatsuba(a1, b1, threshold, &mut z2);
+    let (mut a01, mut b01) = (a1.to_vec(), b1.to_vec());
+    for i in 0..h {
+        a01[i] ^= a0[i];
+        b01[i] ^= b0[i];
+    }
+    karatsuba(&a01, &b01, threshold, &mut z1);
+    for i in 0..2 * h {
+        z1[i] ^= z0[i];
+        c[i] ^= z0[i];
+    }
+    for i in 0..2 * t {
+        z1[i] ^= z2[i];
+    }
+    for i in 0..2 * t {
+        c[h + i] ^= z1[i];
+        c[2 * h + i] ^= z2[i];
+    }
+}
+
+// PCLMULQDQ when the host has it, which also runs in constant time,
+// otherwise Karatsuba multiplication or the masked one of the constant-time backend
+impl<const N: usize> Mul for BinaryPolynomial<N> {
+    type Output = BinaryPolynomial2<N>;
+
+    #[cfg(feature = "constant-time")]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.ct_mul(&rhs)
+    }
+
+    #[cfg(not(feature = "constant-time"))]
+    fn mul(self, rhs: Self) -> Self::Output {
+        trace_op!("mul");
+        if let Some(c) = clmul::mul(&self, &rhs) {
+            return c;
+        }
+        self.karatsuba_mul(&rhs, KARATSUBA_THRESHOLD)
+    }
 }
 
 impl<const N: usize> Shl<usize> for BinaryPolynomial<N> {
@@ -427,6 +719,7 @@ impl<const N: usize> Neg for BinaryPolynomial<N> {
 impl<const N: usize> Sub<Self> for BinaryPolynomial<N> {
     type Output = Self;
 
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn sub(self, rhs: Self) -> Self::Output {
         self + rhs
     }
@@ -455,29 +748,86 @@ impl<const N: usize> BinaryPolynomial2<N> {
 
     // get the degree of binary polynomial
     pub fn degree(&self) -> usize {
-        let high_degree = self.0[1].degree();
-        if high_degree > 0 {
-            high_degree + N
+        if !self.0[1].is_zero() {
+            self.0[1].degree() + N * WORD_SIZE
         } else {
             self.0[0].degree()
         }
     }
 
+    // reduce modulo f(X) = X^m + \sum_{r \in R} X^r word by word, Algorithm 2.42 in "Guide to Elliptic Curve Cryptography"
+    // with the shift amounts derived from m, R and the word size instead of hardcoded for 32-bit words,
+    // which requires m - r >= WORD_SIZE for every r in R so that a word never folds into itself,
+    // it is a const fn so that field constants can be derived at compile time, the i-th word is C[i / N][i % N]
+    pub const fn reduce_sparse(&self, m: usize, R: &[usize]) -> BinaryPolynomial<N> {
+        let mut C = [self.0[0].0, self.0[1].0];
+        // the whole words above X^m, X^{iW + b} = X^{iW + b - m} * \sum_{r \in R} X^r
+        let top = m.div_ceil(WORD_SIZE);
+        let mut i = 2 * N;
+        while i > top {
+            i -= 1;
+            let w = C[i / N][i % N];
+            let mut k = 0;
+            while k < R.len() {
+                debug_assert!(R[k] + WORD_SIZE <= m);
+                let p = i * WORD_SIZE + R[k] - m;
+                let (j, s) = (p / WORD_SIZE, p % WORD_SIZE);
+                C[j / N][j % N] ^= w << s;
+                if s > 0 {
+                    C[(j + 1) / N][(j + 1) % N] ^= w >> (WORD_SIZE - s);
+                }
+                k += 1;
+            }
+        }
+        let mut i = top;
+        while i < N {
+            C[0][i] = 0 as WORD;
+            i += 1;
+        }
+        // the bits of degree >= m left in the word straddling X^m
+        let (j, s) = (m / WORD_SIZE, m % WORD_SIZE);
+        if s > 0 {
+            let T = C[0][j] >> s;
+            C[0][j] &= (1 << s) - 1;
+            let mut k = 0;
+            while k < R.len() {
+                let (i, t) = (R[k] / WORD_SIZE, R[k] % WORD_SIZE);
+                C[0][i] ^= T << t;
+                if t > 0 {
+                    C[0][i + 1] ^= T >> (WORD_SIZE - t);
+                }
+                k += 1;
+            }
+        }
+        BinaryPolynomial(C[0])
+    }
+
+    // remainder modulo f(X) = X^m + \sum_{r \in R} X^r by bitwise long division, the slow reference of reduce_sparse
+    pub fn long_division(&self, m: usize, R: &[usize]) -> BinaryPolynomial<N> {
+        let mut C = *self;
+        for i in (m..2 * N * WORD_SIZE).rev() {
+            if C.bit(i) == 1u8 {
+                for p in std::iter::once(i).chain(R.iter().map(|r| i - m + r)) {
+                    C[p / WORD_SIZE] ^= 1 << (p % WORD_SIZE);
+                }
+            }
+        }
+        C.lower()
+    }
+
     // get one bit of specific offset
     pub fn bit(&self, offset: usize) -> u8 {
         assert!(offset < 2 * N * WORD_SIZE);
-        if offset < N {
+        if offset < N * WORD_SIZE {
             self.0[0].get(offset)
         } else {
-            self.0[1].get(offset - N)
+            self.0[1].get(offset - N * WORD_SIZE)
         }
     }
 
     // swap two binary polynomials
     pub fn swap(&mut self, other: &mut Self) {
-        let tmp = *self;
-        *self = *other;
-        *other = tmp;
+        std::mem::swap(self, other);
     }
 
     // split a BinaryPolynomial2 at a index within the lower one,
@@ -620,17 +970,126 @@ impl<const N: usize> Shr<usize> for BinaryPolynomial2<N> {
     }
 }
 
+// c += b * X^shift word by word, where the shifted b fits in c
+pub(crate) fn xor_shifted<C: IndexMut<usize, Output = WORD> + ?Sized>(
+    c: &mut C,
+    b: &[WORD],
+    shift: usize,
+) {
+    let (k, s) = (shift / WORD_SIZE, shift % WORD_SIZE);
+    for (j, w) in b.iter().enumerate() {
+        if *w == 0 {
+            continue;
+        }
+        c[k + j] ^= w << s;
+        if s > 0 && (w >> (WORD_SIZE - s)) != 0 {
+            c[k + j + 1] ^= w >> (WORD_SIZE - s);
+        }
+    }
+}
+
+impl<const N: usize> BinaryPolynomial2<N> {
+    // remainder modulo any nonzero m(X) by long division, shifting m(X) a word at a time
+    pub fn rem(&self, m: &BinaryPolynomial<N>) -> BinaryPolynomial<N> {
+        assert!(!m.is_zero(), "Division by zero polynomial!");
+        let mut c = *self;
+        let k = m.degree();
+        while !c.is_zero() && c.degree() >= k {
+            let s = c.degree() - k;
+            xor_shifted(&mut c, &m.0, s);
+        }
+        c.lower()
+    }
+}
+
+// Euclidean arithmetic in GF(2)[X] for moduli of any weight, where DynPolynomial is the variant for large degrees
+impl<const N: usize> BinaryPolynomial<N> {
+    // q(X) and r(X) such that self = q(X) * d(X) + r(X) with deg(r) < deg(d)
+    pub fn div_rem(&self, d: &Self) -> (Self, Self) {
+        assert!(!d.is_zero(), "Division by zero polynomial!");
+        let (mut q, mut r) = (Self::zero(), *self);
+        let k = d.degree();
+        while !r.is_zero() && r.degree() >= k {
+            let s = r.degree() - k;
+            q.set(s, 1);
+            xor_shifted(&mut r.0[..], &d.0, s);
+        }
+        (q, r)
+    }
+
+    // gcd with Euclid's algorithm, which is monic over GF(2) unless both are zero
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (*self, *other);
+        while !b.is_zero() {
+            let (_, r) = a.div_rem(&b);
+            (a, b) = (b, r);
+        }
+        a
+    }
+
+    // (g, s, t) such that g = gcd(self, other) = s(X) * self + t(X) * other, Algorithm 2.47 in "Guide to Elliptic Curve Cryptography",
+    // where deg(s) < deg(other) and deg(t) < deg(self) so that the products never overflow N words
+    pub fn xgcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (mut r0, mut r1) = (*self, *other);
+        let (mut s0, mut s1) = (Self::one(), Self::zero());
+        let (mut t0, mut t1) = (Self::zero(), Self::one());
+        while !r1.is_zero() {
+            let (q, r) = r0.div_rem(&r1);
+            (r0, r1) = (r1, r);
+            (s0, s1) = (s1, s0 + (q * s1).lower());
+            (t0, t1) = (t1, t0 + (q * t1).lower());
+        }
+        (r0, s0, t0)
+    }
+
+    // self * rhs (mod m(X))
+    pub fn mul_mod(&self, rhs: &Self, m: &Self) -> Self {
+        (*self * *rhs).rem(m)
+    }
+
+    // self^e (mod m(X)) with left-to-right square and multiply
+    pub fn pow_mod(&self, e: &BigUint, m: &Self) -> Self {
+        let base = self.div_rem(m).1;
+        let mut result = Self::one().div_rem(m).1;
+        for i in (0..e.bits()).rev() {
+            result = result.squaring().rem(m);
+            if e.bit(i) {
+                result = result.mul_mod(&base, m);
+            }
+        }
+        result
+    }
+
+    // self(g(X)) (mod m(X)) with Horner's rule, the plain counterpart of BinaryField::modular_composition
+    pub fn compose_mod(&self, g: &Self, m: &Self) -> Self {
+        let g = g.div_rem(m).1;
+        let mut result = Self::zero();
+        if self.is_zero() {
+            return result;
+        }
+        for i in (0..=self.degree()).rev() {
+            result = result.mul_mod(&g, m);
+            if self.get(i) == 1u8 {
+                result = (result + Self::one()).div_rem(m).1;
+            }
+        }
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::str::FromStr;
-    const N: usize = 6;
+    // 192-bit and 64-bit polynomials whatever the word size is
+    const N: usize = 192 / WORD_SIZE;
+    const N64: usize = 64 / WORD_SIZE;
 
     #[test]
     fn test_hex_string() {
         let test_data = [(
             String::from_str("0x0000000644192702d2623c11c05c3196ee6490c8f4927ce5").unwrap(),
-            BinaryPolynomial([
+            BinaryPolynomial::from_u32_words(&[
                 4103240933, 3999568072, 3227267478, 3529653265, 1142499074, 6,
             ]),
         )];
@@ -647,7 +1106,7 @@ mod tests {
         let test_data = [(
             String::from_str("0b1100100010000011001001001110000001011010010011000100011110000010001110000000101110000110001100101101110111001100100100100001100100011110100100100100111110011100101")
                 .unwrap(),
-            BinaryPolynomial([
+            BinaryPolynomial::from_u32_words(&[
                 4103240933, 3999568072, 3227267478, 3529653265, 1142499074, 6,
             ]),
         )];
@@ -677,11 +1136,11 @@ mod tests {
         )];
         for (u_hex_string, v_hex_string, (w_low_hex_string, w_high_hex_string)) in test_data {
             let (u, v, w_expected) = (
-                BinaryPolynomial::<2>::from_hex_string(&u_hex_string),
-                BinaryPolynomial::<2>::from_hex_string(&v_hex_string),
+                BinaryPolynomial::<N64>::from_hex_string(&u_hex_string),
+                BinaryPolynomial::<N64>::from_hex_string(&v_hex_string),
                 BinaryPolynomial2([
-                    BinaryPolynomial::<2>::from_hex_string(&w_low_hex_string),
-                    BinaryPolynomial::<2>::from_hex_string(&w_high_hex_string),
+                    BinaryPolynomial::<N64>::from_hex_string(&w_low_hex_string),
+                    BinaryPolynomial::<N64>::from_hex_string(&w_high_hex_string),
                 ]),
             );
             let w = u * v;
@@ -691,6 +1150,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_karatsuba_mul() {
+        let u = BinaryPolynomial::<18>::from_hex_string(
+            "0x07a4c43b9f6e1ad2c5e7084b39f2d1c6e5a83b7f0c4d91e2a6b38f5c0d7e194b26a3c8f51e0b7d4a92c63e8f150b2d7a4c9e36f1805b2d4e7a93c6f108b5d2e4a7c93f6b18e05d2a4c7e9b36f1a8c0d5e2b7",
+        );
+        let v = BinaryPolynomial::<18>::from_hex_string(
+            "0x05f1d2e3c4b5a6978869a5b4c3d2e1f00f1e2d3c4b5a69788796a5b4c3d2e1f0ffeeddccbbaa99887766554433221100123456789abcdef0fedcba98765432100112233445566778899aabbccddeeff0",
+        );
+        let w = u.window_mul(&v);
+        // odd and even number of words, and thresholds down to single words
+        for threshold in [0, 1, 2, 3, 4, 5, 9, 18, 32] {
+            assert_eq!(
+                u.karatsuba_mul(&v, threshold),
+                w,
+                "Test for BinaryPolynomial::karatsuba_mul failed!"
+            );
+        }
+        let (u, v) = (
+            BinaryPolynomial::<13>::from(u.0[..13].to_vec()),
+            BinaryPolynomial::<13>::from(v.0[5..].to_vec()),
+        );
+        for threshold in [1, 2, 3, 6, 7] {
+            assert_eq!(u.karatsuba_mul(&v, threshold), u.window_mul(&v));
+        }
+        let ones = BinaryPolynomial([WORD::MAX; 5]);
+        assert_eq!(ones.karatsuba_mul(&ones, 1), ones.squaring());
+    }
+
     // squaring over a byte
     #[test]
     fn test_u8_squaring() {
@@ -701,6 +1188,17 @@ mod tests {
         }
     }
 
+    // squaring over 32-bit and 64-bit words, whose higher halves spread into the higher word
+    #[test]
+    fn test_word_squaring() {
+        assert_eq!(0xffff_0003u32.squaring(), [5, 0x5555_5555]);
+        assert_eq!(0x8000_0001u32.squaring(), [1, 0x4000_0000]);
+        assert_eq!(
+            0x8000_0000_0000_0003u64.squaring(),
+            [5, 0x4000_0000_0000_0000]
+        );
+    }
+
     // squaring over a bigint
     #[test]
     fn test_binary_polynomial_squaring() {
@@ -722,10 +1220,10 @@ mod tests {
         ];
         for (v_hex_string, v_squaring_hex_string) in test_data {
             let (v, v_squaring_expected) = (
-                BinaryPolynomial::<2>::from_hex_string(&v_hex_string),
+                BinaryPolynomial::<N64>::from_hex_string(&v_hex_string),
                 BinaryPolynomial2([
-                    BinaryPolynomial::<2>::from_hex_string(&v_squaring_hex_string[0]),
-                    BinaryPolynomial::<2>::from_hex_string(&v_squaring_hex_string[1]),
+                    BinaryPolynomial::<N64>::from_hex_string(&v_squaring_hex_string[0]),
+                    BinaryPolynomial::<N64>::from_hex_string(&v_squaring_hex_string[1]),
                 ]),
             );
             let v_squaring = v.squaring();
@@ -744,4 +1242,60 @@ mod tests {
         let w = u.trunc_add(1, v);
         assert_eq!(w, w_expected);
     }
+
+    // small cases by hand, (X^2 + X + 1)(X^3 + X + 1) and (X^2 + X + 1)(X + 1), then random ones
+    #[test]
+    fn test_div_rem_gcd() {
+        use rand::Rng;
+        let a = BinaryPolynomial::<N>::from_exponents(&[5, 4, 0]);
+        let b = BinaryPolynomial::<N>::from_exponents(&[3, 0]);
+        assert_eq!(a.gcd(&b), BinaryPolynomial::from_exponents(&[2, 1, 0]));
+        assert_eq!(
+            a.div_rem(&b),
+            (
+                BinaryPolynomial::from_exponents(&[2, 1]),
+                BinaryPolynomial::from_exponents(&[2, 1, 0])
+            )
+        );
+        assert_eq!(a.gcd(&BinaryPolynomial::zero()), a);
+        let mut rng = rand::rng();
+        for _ in 0..16 {
+            // at most 96 bits so that the products fit in N words
+            let mut random = || {
+                BinaryPolynomial::<N>(std::array::from_fn(|i| {
+                    if i < N / 2 { rng.random::<WORD>() } else { 0 }
+                }))
+            };
+            let (a, b) = (random(), random());
+            let (q, r) = a.div_rem(&b);
+            assert!(r.is_zero() || r.degree() < b.degree());
+            assert_eq!((q * b).lower() + r, a);
+            let (g, s, t) = a.xgcd(&b);
+            assert_eq!(g, a.gcd(&b));
+            assert_eq!((s * a).lower() + (t * b).lower(), g);
+            assert!(a.div_rem(&g).1.is_zero() && b.div_rem(&g).1.is_zero());
+        }
+    }
+
+    // against Fq233, where raising to 2^{233} is the identity and composing with X^2 is squaring
+    #[test]
+    fn test_pow_compose_mod() {
+        use crate::binary_field::BinaryField;
+        use crate::binary_field::fq233::{Fq233, M, R};
+        let f = Fq233::F;
+        let a = Fq233::reduce(BinaryPolynomial2::from(BinaryPolynomial::from_hex_string(
+            "0x1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8",
+        )));
+        let e = BigUint::parse_bytes(b"123456789abcdef0fedcba987654321", 16).unwrap();
+        let e_poly = BinaryPolynomial::from_hex_string("0x0123456789abcdef0fedcba987654321");
+        assert_eq!(a.0.pow_mod(&e, &f), a.exp(e_poly).0);
+        assert_eq!(a.0.pow_mod(&(BigUint::from(1u32) << M), &f), a.0);
+        let x2 = BinaryPolynomial::from_exponents(&[2]);
+        assert_eq!(a.0.compose_mod(&x2, &f), a.squaring().0);
+        let x = BinaryPolynomial::from_exponents(&[1]);
+        assert_eq!(a.0.compose_mod(&x, &f), a.0);
+        assert_eq!(a.0.mul_mod(&a.0, &f), a.squaring().0);
+        let b = a.squaring() + a;
+        assert_eq!(a.0.mul_sparse(&b.0, M, R), (a * b).0);
+    }
 }
@@ -0,0 +1,76 @@
+/// Unreduced products of binary field elements, accumulated as double-width polynomials and reduced on demand
+use super::BinaryField;
+use super::polynomial::BinaryPolynomial2;
+use std::marker::PhantomData;
+use std::ops::Add;
+
+// sum of products a_i * b_i in GF(2)[X] with degree at most 2M - 2, which stays so under addition,
+// so that a sum of products only takes one reduction instead of one per product, the fields are private
+// since the bound is only kept by building values from products, squares and reduced elements
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Unreduced<const N: usize, Field: BinaryField<N>> {
+    value: BinaryPolynomial2<N>,
+    marker: PhantomData<Field>,
+}
+
+impl<const N: usize, Field: BinaryField<N>> Unreduced<N, Field> {
+    // the caller makes sure the degree of value is at most 2M - 2
+    pub(crate) fn new(value: BinaryPolynomial2<N>) -> Self {
+        Self {
+            value,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn zero() -> Self {
+        Self::new(BinaryPolynomial2::<N>::zero())
+    }
+
+    // a * b without reduction
+    pub fn product(a: &Field, b: &Field) -> Self {
+        Self::new(a.polynomial() * b.polynomial())
+    }
+
+    // a^2 without reduction
+    pub fn square(a: &Field) -> Self {
+        Self::new(a.polynomial().squaring())
+    }
+
+    // multiply-accumulate, self + a * b
+    pub fn mul_add(self, a: &Field, b: &Field) -> Self {
+        self + Self::product(a, b)
+    }
+
+    // underlying double-width polynomial with degree at most 2M - 2
+    pub fn polynomial(&self) -> BinaryPolynomial2<N> {
+        self.value
+    }
+
+    // reduce modulo the irreducible polynomial of the field
+    pub fn reduce(&self) -> Field {
+        Field::reduce(self.value)
+    }
+}
+
+impl<const N: usize, Field: BinaryField<N>> From<Field> for Unreduced<N, Field> {
+    fn from(v: Field) -> Self {
+        Self::new(BinaryPolynomial2::<N>::from(v.polynomial()))
+    }
+}
+
+impl<const N: usize, Field: BinaryField<N>> Add for Unreduced<N, Field> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.value + rhs.value)
+    }
+}
+
+// adding a reduced element, whose degree is less than M
+impl<const N: usize, Field: BinaryField<N>> Add<Field> for Unreduced<N, Field> {
+    type Output = Self;
+
+    fn add(self, rhs: Field) -> Self::Output {
+        self + Self::from(rhs)
+    }
+}
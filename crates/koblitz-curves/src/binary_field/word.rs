@@ -11,8 +11,9 @@ impl BinaryWord for u8 {
     }
 
     // squaring a byte would blow up two times of its capacity
+    #[allow(clippy::needless_range_loop)]
     fn squaring(&self) -> [Self; 2] {
-        let mut result = [0 as u8; 2];
+        let mut result = [0u8; 2];
         // byte to bits
         let bits = self.to_le_bits();
         // insert zeros in lower byte
@@ -39,8 +40,9 @@ impl BinaryWord for u32 {
             .collect::<Vec<_>>()
     }
     // squaring a byte would blow up two times of its capacity
+    #[allow(clippy::needless_range_loop)]
     fn squaring(&self) -> [Self; 2] {
-        let mut result = [0 as u32; 2];
+        let mut result = [0u32; 2];
         // byte to bits
         let bits = self.to_le_bits();
         // insert zeros in lower byte
@@ -51,7 +53,7 @@ impl BinaryWord for u32 {
         }
         // insert zeros in higher word
         for i in 0..16 {
-            if bits[4 + i] == 1u8 {
+            if bits[16 + i] == 1u8 {
                 result[1] += 1 << (2 * i);
             }
         }
@@ -67,8 +69,9 @@ impl BinaryWord for u64 {
             .collect::<Vec<_>>()
     }
     // squaring a byte would blow up two times of its capacity
+    #[allow(clippy::needless_range_loop)]
     fn squaring(&self) -> [Self; 2] {
-        let mut result = [0 as u64; 2];
+        let mut result = [0u64; 2];
         // byte to bits
         let bits = self.to_le_bits();
         // insert zeros in lower byte
@@ -79,7 +82,7 @@ impl BinaryWord for u64 {
         }
         // insert zeros in higher word
         for i in 0..32 {
-            if bits[4 + i] == 1u8 {
+            if bits[32 + i] == 1u8 {
                 result[1] += 1 << (2 * i);
             }
         }
@@ -0,0 +1,145 @@
+#![allow(non_snake_case)]
+
+/// x-only elliptic curve Diffie-Hellman on binary curves, which never needs y-coordinates
+use crate::binary_curve::curve::BinaryCurve;
+use crate::binary_field::BinaryField;
+use crate::error::Error;
+use crate::scalar_field::ScalarField;
+
+// x-only Diffie-Hellman with the Lopez-Dahab Montgomery ladder, available on every binary curve,
+// the secret only goes through the fixed-length ladder and the branch-free field operations whatever the build is
+pub trait DiffieHellman<const N: usize, Field: BinaryField<N>>: BinaryCurve<N, Field> {
+    // x([k]G), the public key of secret k
+    fn public_x(secret: &Self::Scalar) -> Result<Field, Error> {
+        if secret.is_zero() {
+            return Err(Error::ScalarOutOfRange);
+        }
+        let (X, Z) = Self::x_only_ladder(secret, &Self::GENERATOR.x);
+        Ok(X.ct_mul(&Z.ct_inv()))
+    }
+
+    // x([k]P) where x(P) = peer_x, rejecting invalid peers and low order results
+    fn diffie_hellman(secret: &Self::Scalar, peer_x: &Field) -> Result<Field, Error> {
+        if secret.is_zero() {
+            return Err(Error::ScalarOutOfRange);
+        }
+        Self::validate_x(peer_x)?;
+        let (X, Z) = Self::x_only_ladder(secret, peer_x);
+        // Z = 0 is mapped to x = 0, so a single check rejects both low order results
+        let x = X.ct_mul(&Z.ct_inv());
+        if x.is_zero() {
+            return Err(Error::LowOrderPoint);
+        }
+        Ok(x)
+    }
+
+    // x is the x-coordinate of a point in the subgroup of order n only if
+    // 1. x != 0, otherwise it is the point of order 2
+    // 2. Tr(x + a_2 + a_6 / x^2) = 0, i.e. y^2 + xy = x^3 + a_2 * x^2 + a_6 is solvable
+    // 3. Tr(x) = Tr(a_2), i.e. the point is halvable, which is enough when cofactor is 2
+    // 4. x([n - 1]P) = x(P) when cofactor is 4, since 2E still contains the point of order 2
+    fn validate_x(x: &Field) -> Result<(), Error> {
+        if x.is_zero() {
+            return Err(Error::LowOrderPoint);
+        }
+        if (*x + Self::A2 + Self::A6 / x.squaring()).trace() != 0u8 {
+            return Err(Error::NotOnCurve);
+        }
+        if x.trace() != Self::A2.trace() {
+            return Err(Error::NotInSubgroup);
+        }
+        if Self::COFACTOR > 2 {
+            let (X, Z) = Self::x_only_ladder(&(-Self::Scalar::one()), x);
+            if X != *x * Z {
+                return Err(Error::NotInSubgroup);
+            }
+        }
+        Ok(())
+    }
+
+    // [k]P in x-only projective coordinates (X, Z), where x = x(P)
+    fn x_only_ladder(k: &Self::Scalar, x: &Field) -> (Field, Field) {
+        Self::mont_ladder(k, *x).0
+    }
+}
+
+impl<const N: usize, Field: BinaryField<N>, Curve: BinaryCurve<N, Field>> DiffieHellman<N, Field>
+    for Curve
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{B163, Fn233, FnB163, Fq163, Fq233, K233, K233Point};
+
+    #[test]
+    fn test_diffie_hellman() {
+        // keys and shared secrets from OpenSSL
+        let (a, b) = (
+            Fn233::from_hex_string("0x0a1b2c3d4e5f60718293a4b5c6d7e8f90112233445566778899aabbccd"),
+            Fn233::from_hex_string("0x11223344556677889900aabbccddeeff0011223344556677889900aabb"),
+        );
+        let (a_x, b_x) = (K233::public_x(&a).unwrap(), K233::public_x(&b).unwrap());
+        assert_eq!(
+            b_x,
+            Fq233::from_hex_string(
+                "0x00fe6d2c103ee8b9d0faed054a01c4684bb98dfe9f8abc8dc7b328e08071"
+            ),
+        );
+        let shared = Fq233::from_hex_string(
+            "0x0195c34710637a9ac9c7132a9e270a30837b6a69daa6c54f5b27d2b0b608",
+        );
+        assert_eq!(K233::diffie_hellman(&a, &b_x), Ok(shared));
+        assert_eq!(K233::diffie_hellman(&b, &a_x), Ok(shared));
+
+        let (a, b) = (
+            FnB163::from_hex_string("0x0123456789abcdef0123456789abcdef0123456789"),
+            FnB163::from_hex_string("0x02fedcba9876543210fedcba9876543210fedcba98"),
+        );
+        let shared = Fq163::from_hex_string("0x0232b5926c181de24d5055fa004849f14e416feac8");
+        let b_x = B163::public_x(&b).unwrap();
+        assert_eq!(
+            b_x,
+            Fq163::from_hex_string("0x073db228254856fd6ce9129077f0d25365e702dd00")
+        );
+        assert_eq!(B163::diffie_hellman(&a, &b_x), Ok(shared));
+        assert_eq!(
+            B163::diffie_hellman(&b, &B163::public_x(&a).unwrap()),
+            Ok(shared)
+        );
+        // the ladder agrees with scalar multiplication
+        let (X, Z) = B163::x_only_ladder(&a, &B163::GENERATOR.x);
+        assert_eq!(X / Z, (B163::GENERATOR * a).to_affine().unwrap().0);
+    }
+
+    #[test]
+    fn test_peer_validation() {
+        let k = Fn233::from(0x1234);
+        assert_eq!(
+            K233::diffie_hellman(&Fn233::zero(), &K233::GENERATOR.x),
+            Err(Error::ScalarOutOfRange)
+        );
+        // (0, 1) has order 2 and (1, 0) has order 4
+        assert_eq!(
+            K233::diffie_hellman(&k, &Fq233::zero()),
+            Err(Error::LowOrderPoint)
+        );
+        assert!(K233Point::from_affine(Fq233::one(), Fq233::zero()).is_on_curve());
+        assert_eq!(
+            K233::diffie_hellman(&k, &Fq233::one()),
+            Err(Error::NotInSubgroup)
+        );
+        // P + (0, 1) has order 2n and passes the trace checks
+        let p = K233::GENERATOR * k + K233Point::from_affine(Fq233::zero(), Fq233::one());
+        let x = p.to_affine().unwrap().0;
+        assert_eq!(x.trace(), 0u8);
+        assert_eq!(K233::diffie_hellman(&k, &x), Err(Error::NotInSubgroup));
+        // x without any point on curve
+        let x = (2u64..)
+            .map(|i| Fq233::from_hex_string(&format!("0x{:x}", i)))
+            .find(|x| (*x + K233::A6 / x.squaring()).trace() == 1u8)
+            .unwrap();
+        assert_eq!(K233::diffie_hellman(&k, &x), Err(Error::NotOnCurve));
+    }
+}
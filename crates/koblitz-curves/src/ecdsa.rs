@@ -0,0 +1,612 @@
+#![allow(non_snake_case)]
+
+/// ECDSA over binary elliptic curves, section 4.1 of "SEC 1: Elliptic Curve Cryptography",
+/// with deterministic nonces of RFC 6979 and public key recovery
+use crate::binary_curve::curve::{BinaryCurve, ProjectivePoint};
+use crate::binary_field::BinaryField;
+use crate::error::Error;
+use crate::scalar_field::ScalarField;
+use hmac::digest::core_api::BlockSizeUser;
+use hmac::{Mac, SimpleHmac};
+use num_bigint::BigUint;
+use num_traits::{ToPrimitive, Zero};
+use sha2::Digest;
+
+// (r, s) with 1 <= r, s < n
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature<Scalar: ScalarField> {
+    r: Scalar,
+    s: Scalar,
+}
+
+// bit 0 is \tilde{y} = lsb(y / x) of R = [k]G, and the other bits are j where x(R) = r + j * n as integers,
+// there are more than 2 candidates since x(R) ranges over [0, 2^M) which is much larger than n
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RecoveryId(pub u8);
+
+impl<Scalar: ScalarField> Signature<Scalar> {
+    pub fn new(r: Scalar, s: Scalar) -> Result<Self, Error> {
+        if r.is_zero() || s.is_zero() {
+            return Err(Error::InvalidSignature);
+        }
+        Ok(Self { r, s })
+    }
+
+    pub fn r(&self) -> Scalar {
+        self.r
+    }
+
+    pub fn s(&self) -> Scalar {
+        self.s
+    }
+
+    // s <= (n - 1) / 2
+    pub fn is_low_s(&self) -> bool {
+        self.s.to_biguint() <= (Scalar::modulus() >> 1)
+    }
+
+    // (r, s) and (r, n - s) are both valid, keep the one with s <= (n - 1) / 2
+    pub fn normalize_s(&self) -> Self {
+        if self.is_low_s() {
+            return *self;
+        }
+        Self {
+            r: self.r,
+            s: -self.s,
+        }
+    }
+
+    // fixed width encoding r || s, each of which has (BITS + 7) / 8 big ending bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [self.r.to_be_bytes(), self.s.to_be_bytes()].concat()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let l = Scalar::BITS.div_ceil(8);
+        if bytes.len() != 2 * l {
+            return Err(Error::InvalidEncoding);
+        }
+        Self::from_integers(
+            &BigUint::from_bytes_be(&bytes[..l]),
+            &BigUint::from_bytes_be(&bytes[l..]),
+        )
+    }
+
+    // DER encoding of SEQUENCE { r INTEGER, s INTEGER }
+    pub fn to_der(&self) -> Vec<u8> {
+        let content = [
+            der_encode(0x02, &der_integer(&self.r.to_biguint())),
+            der_encode(0x02, &der_integer(&self.s.to_biguint())),
+        ]
+        .concat();
+        der_encode(0x30, &content)
+    }
+
+    // strict DER decoding, i.e. minimal lengths and minimal positive integers without trailing bytes
+    pub fn from_der(bytes: &[u8]) -> Result<Self, Error> {
+        let (content, rest) = der_decode(0x30, bytes)?;
+        let (r, content) = der_decode(0x02, content)?;
+        let (s, content) = der_decode(0x02, content)?;
+        if !rest.is_empty() || !content.is_empty() {
+            return Err(Error::InvalidEncoding);
+        }
+        Self::from_integers(&der_uint(r)?, &der_uint(s)?)
+    }
+
+    fn from_integers(r: &BigUint, s: &BigUint) -> Result<Self, Error> {
+        let n = Scalar::modulus();
+        if r >= &n || s >= &n {
+            return Err(Error::InvalidSignature);
+        }
+        Self::new(Scalar::from_biguint(r), Scalar::from_biguint(s))
+    }
+}
+
+// public key Q = [d]G, which is a finite point of the prime order subgroup
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VerifyingKey<const N: usize, Field: BinaryField<N>, Curve: BinaryCurve<N, Field>> {
+    point: ProjectivePoint<N, Field, Curve>,
+}
+
+impl<const N: usize, Field: BinaryField<N>, Curve: BinaryCurve<N, Field>>
+    VerifyingKey<N, Field, Curve>
+{
+    // rejects the identity, points off curve and points out of the subgroup of order n, i.e. [n - 1]Q != -Q
+    pub fn from_point(p: &ProjectivePoint<N, Field, Curve>) -> Result<Self, Error> {
+        let (x, y) = p.to_affine().ok_or(Error::IdentityPoint)?;
+        let point = ProjectivePoint::from_affine(x, y);
+        if !point.is_on_curve() {
+            return Err(Error::NotOnCurve);
+        }
+        if point * (-Curve::Scalar::one()) != -point {
+            return Err(Error::NotInSubgroup);
+        }
+        Ok(Self { point })
+    }
+
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_point(&ProjectivePoint::from_sec1_bytes(bytes)?)
+    }
+
+    pub fn to_sec1_bytes(&self, compressed: bool) -> Vec<u8> {
+        self.point.to_sec1_bytes(compressed)
+    }
+
+    pub fn as_point(&self) -> &ProjectivePoint<N, Field, Curve> {
+        &self.point
+    }
+
+    pub fn verify<D: Digest>(
+        &self,
+        msg: &[u8],
+        signature: &Signature<Curve::Scalar>,
+    ) -> Result<(), Error> {
+        self.verify_prehash(&D::digest(msg), signature)
+    }
+
+    // R = [e * s^{-1}]G + [r * s^{-1}]Q, accept if and only if x(R) = r (mod n)
+    pub fn verify_prehash(
+        &self,
+        prehash: &[u8],
+        signature: &Signature<Curve::Scalar>,
+    ) -> Result<(), Error> {
+        let e = hash_to_scalar::<Curve::Scalar>(prehash);
+        let s_inv = signature.s.try_inv()?;
+        let R = Curve::GENERATOR * (e * s_inv) + self.point * (signature.r * s_inv);
+        match R.to_affine() {
+            Some((x, _)) if x_to_scalar::<N, Field, Curve::Scalar>(&x) == signature.r => Ok(()),
+            _ => Err(Error::InvalidSignature),
+        }
+    }
+
+    pub fn recover<D: Digest>(
+        msg: &[u8],
+        signature: &Signature<Curve::Scalar>,
+        recovery_id: RecoveryId,
+    ) -> Result<Self, Error> {
+        Self::recover_from_prehash(&D::digest(msg), signature, recovery_id)
+    }
+
+    // Q = r^{-1} * ([s]R - [e]G), where R is decompressed from x(R) = r + j * n and \tilde{y}
+    pub fn recover_from_prehash(
+        prehash: &[u8],
+        signature: &Signature<Curve::Scalar>,
+        recovery_id: RecoveryId,
+    ) -> Result<Self, Error> {
+        let x = signature.r.to_biguint() + Curve::Scalar::modulus() * (recovery_id.0 >> 1);
+        let l = Field::M.div_ceil(8);
+        if x.bits() as usize > Field::M {
+            return Err(Error::InvalidSignature);
+        }
+        let x_bytes = x.to_bytes_be();
+        let mut bytes = vec![0u8; l + 1];
+        bytes[0] = 0x02 | (recovery_id.0 & 1);
+        bytes[(l + 1 - x_bytes.len())..].copy_from_slice(&x_bytes);
+        let R = ProjectivePoint::<N, Field, Curve>::from_sec1_bytes(&bytes)?;
+        let e = hash_to_scalar::<Curve::Scalar>(prehash);
+        let r_inv = signature.r.try_inv()?;
+        let Q = Curve::GENERATOR * (-e * r_inv) + R * (signature.s * r_inv);
+        let verifying_key = Self::from_point(&Q)?;
+        verifying_key.verify_prehash(prehash, signature)?;
+        Ok(verifying_key)
+    }
+}
+
+// secret key d with 1 <= d < n, [d]G and the nonce points [k]G go through the fixed-length ladder over
+// branch-free field operations, but the scalar arithmetic modulo n (reduction, s = k^{-1} * (e + r * d) and
+// RFC 6979 nonce generation) uses variable-time BigUint and is not constant time
+#[derive(Clone)]
+pub struct SigningKey<const N: usize, Field: BinaryField<N>, Curve: BinaryCurve<N, Field>> {
+    secret: Curve::Scalar,
+    verifying_key: VerifyingKey<N, Field, Curve>,
+    // normalize s to the lower half of [1, n) for non-malleable signatures
+    low_s: bool,
+}
+
+impl<const N: usize, Field: BinaryField<N>, Curve: BinaryCurve<N, Field>>
+    SigningKey<N, Field, Curve>
+{
+    pub fn from_scalar(secret: Curve::Scalar) -> Result<Self, Error> {
+        if secret.is_zero() {
+            return Err(Error::ScalarOutOfRange);
+        }
+        // [d]G is always a finite point of the subgroup
+        let (x, y) = Curve::ladder_scalar_mul(&Curve::GENERATOR, secret)
+            .to_affine()
+            .ok_or(Error::IdentityPoint)?;
+        let verifying_key = VerifyingKey {
+            point: ProjectivePoint::from_affine(x, y),
+        };
+        Ok(Self {
+            secret,
+            verifying_key,
+            low_s: false,
+        })
+    }
+
+    // key generation with 64 extra random bits, so that the bias of reduction modulo n is negligible
+    pub fn random<R: rand::RngCore + rand::CryptoRng>(rng: &mut R) -> Self {
+        let mut bytes = vec![0u8; Curve::Scalar::BITS.div_ceil(8) + 8];
+        loop {
+            rng.fill_bytes(&mut bytes);
+            if let Ok(key) = Self::from_scalar(Curve::Scalar::from_be_bytes_mod_order(&bytes)) {
+                return key;
+            }
+        }
+    }
+
+    // big ending bytes with fixed length (BITS + 7) / 8
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != Curve::Scalar::BITS.div_ceil(8) {
+            return Err(Error::InvalidEncoding);
+        }
+        let d = BigUint::from_bytes_be(bytes);
+        if d >= Curve::Scalar::modulus() {
+            return Err(Error::ScalarOutOfRange);
+        }
+        Self::from_scalar(Curve::Scalar::from_biguint(&d))
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.secret.to_be_bytes()
+    }
+
+    pub fn with_low_s(self, low_s: bool) -> Self {
+        Self { low_s, ..self }
+    }
+
+    pub fn verifying_key(&self) -> &VerifyingKey<N, Field, Curve> {
+        &self.verifying_key
+    }
+
+    pub fn sign<D: Digest + BlockSizeUser>(&self, msg: &[u8]) -> Signature<Curve::Scalar> {
+        self.sign_prehash::<D>(&D::digest(msg))
+    }
+
+    pub fn sign_recoverable<D: Digest + BlockSizeUser>(
+        &self,
+        msg: &[u8],
+    ) -> (Signature<Curve::Scalar>, RecoveryId) {
+        self.sign_prehash_recoverable::<D>(&D::digest(msg))
+    }
+
+    // D is the hash function of HMAC in RFC 6979, usually the one producing the prehash
+    pub fn sign_prehash<D: Digest + BlockSizeUser>(
+        &self,
+        prehash: &[u8],
+    ) -> Signature<Curve::Scalar> {
+        self.sign_prehash_recoverable::<D>(prehash).0
+    }
+
+    // r = x(R) (mod n) and s = k^{-1} * (e + r * d) (mod n), where R = [k]G
+    pub fn sign_prehash_recoverable<D: Digest + BlockSizeUser>(
+        &self,
+        prehash: &[u8],
+    ) -> (Signature<Curve::Scalar>, RecoveryId) {
+        let e = hash_to_scalar::<Curve::Scalar>(prehash);
+        let mut nonces = Rfc6979::<D>::new(&self.secret, prehash);
+        loop {
+            let k = nonces.next::<Curve::Scalar>();
+            let Some((x, y)) = Curve::ladder_scalar_mul(&Curve::GENERATOR, k).to_affine() else {
+                continue;
+            };
+            let r = x_to_scalar::<N, Field, Curve::Scalar>(&x);
+            let s = blinded_inv(&k) * (e + r * self.secret);
+            let Ok(signature) = Signature::new(r, s) else {
+                continue;
+            };
+            let R = ProjectivePoint::<N, Field, Curve>::from_affine(x, y);
+            let j = (BigUint::from_bytes_be(&x.to_be_bytes()) / Curve::Scalar::modulus())
+                .to_u8()
+                .expect("x(R) / n is small since x(R) < 2^M");
+            let recovery_id = RecoveryId((R.to_sec1_bytes(true)[0] & 1) | (j << 1));
+            // (r, -s) is signed with -R = (x, x + y), whose \tilde{y} is flipped
+            if self.low_s && !signature.is_low_s() {
+                return (signature.normalize_s(), RecoveryId(recovery_id.0 ^ 1));
+            }
+            return (signature, recovery_id);
+        }
+    }
+}
+
+// HMAC_DRBG based nonce generation, section 3.2 of RFC 6979
+struct Rfc6979<D: Digest + BlockSizeUser> {
+    k: Vec<u8>,
+    v: Vec<u8>,
+    marker: std::marker::PhantomData<D>,
+}
+
+impl<D: Digest + BlockSizeUser> Rfc6979<D> {
+    fn new<Scalar: ScalarField>(secret: &Scalar, prehash: &[u8]) -> Self {
+        let hlen = <D as Digest>::output_size();
+        let (mut k, mut v) = (vec![0x00u8; hlen], vec![0x01u8; hlen]);
+        // int2octets(x) and bits2octets(h1) = int2octets(bits2int(h1) mod q)
+        let x = secret.to_be_bytes();
+        let h = hash_to_scalar::<Scalar>(prehash).to_be_bytes();
+        for t in [0x00u8, 0x01u8] {
+            k = hmac::<D>(&k, &[&v, &[t], &x, &h]);
+            v = hmac::<D>(&k, &[&v]);
+        }
+        Self {
+            k,
+            v,
+            marker: std::marker::PhantomData,
+        }
+    }
+
+    // next k = bits2int(T) with 1 <= k < q
+    fn next<Scalar: ScalarField>(&mut self) -> Scalar {
+        let rlen = Scalar::BITS.div_ceil(8);
+        loop {
+            let mut t = vec![];
+            while t.len() < rlen {
+                self.v = hmac::<D>(&self.k, &[&self.v]);
+                t.extend_from_slice(&self.v);
+            }
+            let k = bits2int(&t, Scalar::BITS);
+            let candidate =
+                (!k.is_zero() && k < Scalar::modulus()).then(|| Scalar::from_biguint(&k));
+            self.k = hmac::<D>(&self.k, &[&self.v, &[0x00]]);
+            self.v = hmac::<D>(&self.k, &[&self.v]);
+            if let Some(k) = candidate {
+                return k;
+            }
+        }
+    }
+}
+
+fn hmac<D: Digest + BlockSizeUser>(key: &[u8], data: &[&[u8]]) -> Vec<u8> {
+    let mut mac =
+        <SimpleHmac<D> as Mac>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    for d in data {
+        mac.update(d);
+    }
+    mac.finalize().into_bytes().to_vec()
+}
+
+// leftmost qlen bits of a big ending byte string as an integer
+fn bits2int(bytes: &[u8], qlen: usize) -> BigUint {
+    let v = BigUint::from_bytes_be(bytes);
+    if bytes.len() * 8 > qlen {
+        v >> (bytes.len() * 8 - qlen)
+    } else {
+        v
+    }
+}
+
+// k^{-1} = (k * b)^{-1} * b for a random b, so that the variable-time inversion never sees the nonce itself,
+// the multiplications modulo n around it are still variable time
+fn blinded_inv<Scalar: ScalarField>(k: &Scalar) -> Scalar {
+    let mut bytes = vec![0u8; Scalar::BITS.div_ceil(8) + 8];
+    loop {
+        rand::RngCore::fill_bytes(&mut rand::rng(), &mut bytes);
+        let b = Scalar::from_be_bytes_mod_order(&bytes);
+        if !b.is_zero() {
+            return (*k * b).inv() * b;
+        }
+    }
+}
+
+// e = bits2int(H(m)) (mod n)
+fn hash_to_scalar<Scalar: ScalarField>(prehash: &[u8]) -> Scalar {
+    Scalar::from_biguint(&bits2int(prehash, Scalar::BITS))
+}
+
+// x(R) as an integer reduced modulo n
+fn x_to_scalar<const N: usize, Field: BinaryField<N>, Scalar: ScalarField>(x: &Field) -> Scalar {
+    Scalar::from_be_bytes_mod_order(&x.to_be_bytes())
+}
+
+// minimal big ending bytes of a positive integer, with a leading zero if the highest bit is set
+fn der_integer(v: &BigUint) -> Vec<u8> {
+    let bytes = v.to_bytes_be();
+    if bytes[0] & 0x80 != 0 {
+        [vec![0x00], bytes].concat()
+    } else {
+        bytes
+    }
+}
+
+fn der_uint(bytes: &[u8]) -> Result<BigUint, Error> {
+    match bytes {
+        [] => Err(Error::InvalidEncoding),
+        [b, ..] if b & 0x80 != 0 => Err(Error::InvalidEncoding),
+        [0x00, b, ..] if b & 0x80 == 0 => Err(Error::InvalidEncoding),
+        _ => Ok(BigUint::from_bytes_be(bytes)),
+    }
+}
+
+// tag || length || content, with short form length below 128 and long form up to 65535
+fn der_encode(tag: u8, content: &[u8]) -> Vec<u8> {
+    let l = content.len();
+    let length = match l {
+        0..0x80 => vec![l as u8],
+        0x80..0x100 => vec![0x81, l as u8],
+        _ => vec![0x82, (l >> 8) as u8, l as u8],
+    };
+    [vec![tag], length, content.to_vec()].concat()
+}
+
+// returns the content with expected tag and the remaining bytes
+fn der_decode(tag: u8, bytes: &[u8]) -> Result<(&[u8], &[u8]), Error> {
+    let (l, offset) = match bytes {
+        [t, l, ..] if *t == tag && *l < 0x80 => (*l as usize, 2),
+        [t, 0x81, l, ..] if *t == tag && *l >= 0x80 => (*l as usize, 3),
+        [t, 0x82, h, l, ..] if *t == tag && *h > 0 => (((*h as usize) << 8) | *l as usize, 4),
+        _ => return Err(Error::InvalidEncoding),
+    };
+    if bytes.len() < offset + l {
+        return Err(Error::InvalidEncoding);
+    }
+    Ok((&bytes[offset..offset + l], &bytes[offset + l..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary_curve::k233::{K233SigningKey, K233VerifyingKey};
+    use crate::scalar_field::fn233::Fn233;
+    use sha2::{Sha256, Sha512};
+
+    // private key of K-233 in appendix A.2.9 of RFC 6979
+    fn rfc6979_key() -> K233SigningKey {
+        K233SigningKey::from_bytes(
+            &hex::decode("103b2142bdc2a3c3b55080d09df1808f79336da2399f5ca7171d1be9b0").unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_rfc6979() {
+        let sk = rfc6979_key();
+        assert_eq!(
+            sk.verifying_key().to_sec1_bytes(true),
+            hex::decode("0300682886f36c68473c1a221720c2b12b9be13458ba907e1c4736595779f2").unwrap()
+        );
+        let test_data = [
+            (
+                sk.sign::<Sha256>(b"sample"),
+                "38ad9c1d2cb29906e7d63c24601ac55736b438fb14f4093d6c32f63a10",
+                "647aad2599c21b6ee89be7ff957d98f684b7921de1fd3cc82c079624f4",
+            ),
+            (
+                sk.sign::<Sha512>(b"sample"),
+                "47c4ac1b344028cc740ba7bb9f8aa59d6390e3158153d4f2ade4b74950",
+                "26ce0cde18a1b884b3ee1a879c13b42f11bb7c85f7a3745c8becec8e6e",
+            ),
+            (
+                sk.sign::<Sha256>(b"test"),
+                "05e4e6b4db0e13034e7f1f2e5dbab766d37c15ae4056c7ee607c8ac7f4",
+                "5fc46aa489bf828b34fbad25ec432190f161bea8f60d3fcadb0ee3b725",
+            ),
+            (
+                sk.sign::<Sha512>(b"test"),
+                "6fe6d0d3a953bb66bb01bc6b9edfad9f35e88277e5768d1b214395320f",
+                "7c01a236e4bff0a771050ad01ec1d24025d3130bbd9e4e81978eb3ec09",
+            ),
+        ];
+        for (signature, r, s) in test_data {
+            assert_eq!(
+                signature.r,
+                Fn233::from_hex_string(&format!("0x{}", r)),
+                "Test for RFC 6979 r failed!"
+            );
+            assert_eq!(
+                signature.s,
+                Fn233::from_hex_string(&format!("0x{}", s)),
+                "Test for RFC 6979 s failed!"
+            );
+        }
+        let vk = sk.verifying_key();
+        let signature = sk.sign::<Sha256>(b"sample");
+        assert_eq!(vk.verify::<Sha256>(b"sample", &signature), Ok(()));
+        assert_eq!(
+            vk.verify::<Sha256>(b"samples", &signature),
+            Err(Error::InvalidSignature)
+        );
+        assert_eq!(
+            vk.verify::<Sha512>(b"sample", &signature),
+            Err(Error::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_encoding() {
+        let sk = rfc6979_key();
+        let signature = sk.sign::<Sha256>(b"sample");
+        // DER produced by OpenSSL with deterministic nonces
+        let der = hex::decode("303e021d38ad9c1d2cb29906e7d63c24601ac55736b438fb14f4093d6c32f63a10021d647aad2599c21b6ee89be7ff957d98f684b7921de1fd3cc82c079624f4").unwrap();
+        assert_eq!(signature.to_der(), der);
+        assert_eq!(Signature::from_der(&der), Ok(signature));
+        assert_eq!(Signature::from_bytes(&signature.to_bytes()), Ok(signature));
+        assert_eq!(signature.to_bytes().len(), 58);
+        // integers with the highest bit set get a leading zero
+        let signature = Signature::new(-Fn233::one(), Fn233::one()).unwrap();
+        let der = signature.to_der();
+        assert_eq!(&der[..5], &[0x30, 0x23, 0x02, 0x1e, 0x00]);
+        assert_eq!(Signature::from_der(&der), Ok(signature));
+        // trailing bytes, non-minimal integers and out of range scalars are rejected
+        let mut der = signature.to_der();
+        der.push(0x00);
+        assert_eq!(
+            Signature::<Fn233>::from_der(&der),
+            Err(Error::InvalidEncoding)
+        );
+        let der = hex::decode("3007020200010201010000").unwrap();
+        assert_eq!(
+            Signature::<Fn233>::from_der(&der[..9]),
+            Err(Error::InvalidEncoding)
+        );
+        let der = hex::decode("3006020100020101").unwrap();
+        assert_eq!(
+            Signature::<Fn233>::from_der(&der),
+            Err(Error::InvalidSignature)
+        );
+        let mut bytes = signature.to_bytes();
+        bytes[..29].copy_from_slice(&Fn233::modulus().to_bytes_be());
+        assert_eq!(
+            Signature::<Fn233>::from_bytes(&bytes),
+            Err(Error::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_low_s_and_recovery() {
+        let mut rng = rand::rng();
+        for _ in 0..4 {
+            let sk = K233SigningKey::random(&mut rng);
+            let vk = sk.verifying_key();
+            let msg = b"low s and public key recovery";
+            let (signature, recovery_id) = sk.sign_recoverable::<Sha256>(msg);
+            let (low_signature, low_recovery_id) =
+                sk.clone().with_low_s(true).sign_recoverable::<Sha256>(msg);
+            assert!(low_signature.is_low_s());
+            assert_eq!(low_signature, signature.normalize_s());
+            for (signature, recovery_id) in
+                [(signature, recovery_id), (low_signature, low_recovery_id)]
+            {
+                assert_eq!(vk.verify::<Sha256>(msg, &signature), Ok(()));
+                assert_eq!(
+                    K233VerifyingKey::recover::<Sha256>(msg, &signature, recovery_id).as_ref(),
+                    Ok(vk),
+                    "Test for public key recovery failed!"
+                );
+                let wrong_id = RecoveryId(recovery_id.0 ^ 1);
+                assert_ne!(
+                    K233VerifyingKey::recover::<Sha256>(msg, &signature, wrong_id).as_ref(),
+                    Ok(vk)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_key_validation() {
+        let n = Fn233::modulus().to_bytes_be();
+        assert_eq!(
+            K233SigningKey::from_bytes(&n).err(),
+            Some(Error::ScalarOutOfRange)
+        );
+        assert_eq!(
+            K233SigningKey::from_bytes(&[0u8; 29]).err(),
+            Some(Error::ScalarOutOfRange)
+        );
+        assert_eq!(
+            K233SigningKey::from_bytes(&[1u8; 30]).err(),
+            Some(Error::InvalidEncoding)
+        );
+        // points of order 2 and 4 are on curve but out of the subgroup
+        let t = crate::K233Point::from_affine(crate::Fq233::zero(), crate::K233::A6_SQRT);
+        assert_eq!(
+            K233VerifyingKey::from_sec1_bytes(&t.to_sec1_bytes(true)),
+            Err(Error::NotInSubgroup)
+        );
+        let vk = rfc6979_key().verifying_key().to_owned();
+        let q = *vk.as_point() + t;
+        assert_eq!(K233VerifyingKey::from_point(&q), Err(Error::NotInSubgroup));
+        assert_eq!(
+            K233VerifyingKey::from_sec1_bytes(&vk.to_sec1_bytes(false)),
+            Ok(vk)
+        );
+    }
+}
@@ -0,0 +1,58 @@
+/// Errors raised on malformed input and on partial arithmetic, so that callers can reject them instead of panicking
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    // hex string without "0x" prefix, or with characters other than hex digits
+    InvalidHexString,
+    // binary polynomial whose degree is beyond what the field can reduce
+    DegreeTooLarge,
+    // zero in a field, or an integer sharing a factor with the modulus
+    NotInvertible,
+    // reduction polynomial of a runtime binary field which is not irreducible
+    NotIrreducible,
+    // denominator is zero
+    DivisionByZero,
+    // byte string with unexpected length or prefix
+    InvalidEncoding,
+    // point at infinity where a finite point is required
+    IdentityPoint,
+    // coordinates which do not satisfy the curve equation
+    NotOnCurve,
+    // point on curve but out of the prime order subgroup
+    NotInSubgroup,
+    // point of small order, such as the result of Diffie-Hellman with a malicious peer
+    LowOrderPoint,
+    // curve parameter left unset in a builder
+    MissingParameter,
+    // singular curve, or a generator whose order is not the given one
+    InvalidCurveParameters,
+    // integer out of the range [1, n) of secret keys
+    ScalarOutOfRange,
+    // signature out of range or failing verification
+    InvalidSignature,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            Error::InvalidHexString => "invalid hex string",
+            Error::DegreeTooLarge => "degree of binary polynomial is too large",
+            Error::NotInvertible => "element is not invertible",
+            Error::NotIrreducible => "polynomial is not irreducible",
+            Error::DivisionByZero => "division by zero",
+            Error::InvalidEncoding => "invalid encoding",
+            Error::IdentityPoint => "point at infinity",
+            Error::NotOnCurve => "point is not on curve",
+            Error::NotInSubgroup => "point is not in the prime order subgroup",
+            Error::LowOrderPoint => "point has low order",
+            Error::MissingParameter => "curve parameter is missing",
+            Error::InvalidCurveParameters => "invalid curve parameters",
+            Error::ScalarOutOfRange => "scalar is out of range",
+            Error::InvalidSignature => "invalid signature",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+impl std::error::Error for Error {}
@@ -1,2 +1,50 @@
-mod binary_curve;
-mod binary_field;
+//! Binary field arithmetic, binary elliptic curves and \tau-adic expansions for Koblitz curves.
+//!
+//! The commonly used types are re-exported at the crate root, and `prelude` pulls in the traits
+//! required to call field and curve operations.
+
+pub mod binary_curve;
+pub mod binary_field;
+pub mod ecdh;
+pub mod ecdsa;
+pub mod error;
+pub mod scalar_field;
+
+pub use binary_curve::b163::{B163, B163Point};
+pub use binary_curve::b233::{B233, B233Point};
+pub use binary_curve::b283::{B283, B283Point};
+pub use binary_curve::b409::{B409, B409Point};
+pub use binary_curve::b571::{B571, B571Point};
+pub use binary_curve::curve::{BinaryCurve, ProjectivePoint};
+pub use binary_curve::k163::{K163, K163Point};
+pub use binary_curve::k233::{K233, K233Point, K233SigningKey, K233VerifyingKey};
+pub use binary_curve::k283::{K283, K283Point};
+pub use binary_curve::k409::{K409, K409Point};
+pub use binary_curve::k571::{K571, K571Point};
+pub use binary_curve::koblitz::{KoblitzCurve, TauNafwCache, TauNafwTable};
+pub use binary_curve::koblitz_tau::{
+    Koblitz, LucasSequence, Norm, R, RTau, Tau, Z, ZTau, ZTauExpansion,
+};
+pub use binary_curve::params::{CurveParameters, CurveParametersBuilder};
+pub use binary_field::dynamic::{DynBinaryField, DynElement, DynPolynomial};
+pub use binary_field::polynomial::{BinaryPolynomial, BinaryPolynomial2};
+pub use binary_field::unreduced::Unreduced;
+pub use binary_field::{BinaryField, BinaryWord};
+pub use binary_field::{fq163::Fq163, fq233::Fq233, fq283::Fq283, fq409::Fq409, fq571::Fq571};
+pub use ecdh::DiffieHellman;
+pub use ecdsa::{RecoveryId, Signature, SigningKey, VerifyingKey};
+pub use error::Error;
+pub use scalar_field::{
+    ScalarField, fn163::Fn163, fn233::Fn233, fn283::Fn283, fn409::Fn409, fn571::Fn571,
+    fnb163::FnB163, fnb233::FnB233, fnb283::FnB283, fnb409::FnB409, fnb571::FnB571,
+};
+
+pub mod prelude {
+    pub use crate::{
+        B163, B163Point, B233, B233Point, B283, B283Point, B409, B409Point, B571, B571Point,
+        BinaryCurve, BinaryField, Fn163, Fn233, Fn283, Fn409, Fn571, FnB163, FnB233, FnB283,
+        FnB409, FnB571, Fq163, Fq233, Fq283, Fq409, Fq571, K163, K163Point, K233, K233Point, K283,
+        K283Point, K409, K409Point, K571, K571Point, Koblitz, KoblitzCurve, Norm, ProjectivePoint,
+        ScalarField, Tau, TauNafwCache, TauNafwTable, Z, ZTau, ZTauExpansion,
+    };
+}
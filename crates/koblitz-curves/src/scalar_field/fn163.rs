@@ -0,0 +1,42 @@
+/// Scalar field of K-163 curve, i.e. Z / nZ where n is the order of its prime subgroup
+/// n = 0x4000000000000000000020108a2e0cc0d99f8a5ef
+use super::{ScalarField, impl_scalar_field};
+
+// little ending u32 limbs of a canonical representative in [0, n), kept private so that every value is reduced
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Fn163([u32; 6]);
+
+impl Fn163 {
+    pub const MODULUS: [u32; 6] = [2583209455, 2732641293, 131336, 0, 0, 4];
+}
+
+impl_scalar_field!(Fn163, 6, 163);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fn_arithmetic() {
+        let n = Fn163::from_hex_string("0x4000000000000000000020108a2e0cc0d99f8a5ef");
+        assert!(n.is_zero(), "Modulus should be reduced to zero!");
+        let (u, v) = (
+            Fn163::from_hex_string("0x03921c1d753700b272aa99548f84addb96587823a5"),
+            Fn163::from_hex_string("0x026484fb838cdb9db1db3b1feb0b96660dc3dc69e0"),
+        );
+        let test_data = [
+            (u + v, "0x01f6a118f8c3dc502485d27371ed637596825be796"),
+            (u - v, "0x012d9721f1aa2514c0cf5e34a479177588949bb9c5"),
+            (u * v, "0x026cc91b92a5ec955eb4393daec56e5bd5b12a4019"),
+            (u.inv(), "0x025902705a52bf6957ebe41e4dc53bd002a28ca9f5"),
+        ];
+        for (w, w_expected_hex_string) in test_data {
+            assert_eq!(
+                w.to_hex_string(),
+                w_expected_hex_string,
+                "Test for Fn163 arithmetic failed!"
+            );
+        }
+        assert_eq!(Fn163::from_be_bytes_mod_order(&u.to_be_bytes()), u);
+    }
+}
@@ -0,0 +1,95 @@
+/// Scalar field of K-233 curve, i.e. Z / nZ where n is the order of its prime subgroup
+/// n = 0x8000000000000000000000000000069d5bb915bcd46efb1ad5f173abdf
+use super::{ScalarField, impl_scalar_field};
+
+// little ending u32 limbs of a canonical representative in [0, n), kept private so that every value is reduced
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Fn233([u32; 8]);
+
+impl Fn233 {
+    pub const MODULUS: [u32; 8] = [4050889695, 1861950165, 3105209556, 433499, 0, 0, 0, 128];
+}
+
+impl_scalar_field!(Fn233, 8, 232);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fn_reduce() {
+        let n =
+            Fn233::from_hex_string("0x8000000000000000000000000000069d5bb915bcd46efb1ad5f173abdf");
+        assert!(n.is_zero(), "Modulus should be reduced to zero!");
+        let v = Fn233::from_hex_string(
+            "0x0000017c14c59e6253fa1903f05141fd556d02d1aec2c77b038098981ecf8166",
+        );
+        assert_eq!(
+            v.to_hex_string(),
+            "0x7c14c59e6253fa1903f05141fd555fc81a3c974dd2258a62ec3be829a8"
+        );
+        let bytes = hex::decode("0000017c14c59e6253fa1903f05141fd556d02d1aec2c77b038098981ecf8166")
+            .unwrap();
+        assert_eq!(Fn233::from_be_bytes_mod_order(&bytes), v);
+        assert_eq!(Fn233::from_be_bytes_mod_order(&v.to_be_bytes()), v);
+        // n + 5 and 5 are the same scalar with the same bits
+        let u =
+            Fn233::from_hex_string("0x8000000000000000000000000000069d5bb915bcd46efb1ad5f173abe4");
+        assert_eq!(u, Fn233::from(5));
+        assert_eq!(u.bits(false), Fn233::from(5).bits(false));
+        assert_eq!((0..3).map(|i| u.bit(i)).collect::<Vec<_>>(), [1, 0, 1]);
+    }
+
+    #[test]
+    fn test_fn_arithmetic() {
+        let (u, v) = (
+            Fn233::from_hex_string("0x3bd4f59063516f81a1621a4d4885e77e0f4693f893b656abe82c4e5c2f"),
+            Fn233::from_hex_string("0x131fb97cdb584763a0dbfe94f6a78ec31d680ecf7c0df07dafb5b418b09"),
+        );
+        let test_data = [
+            (
+                u + v,
+                "0x6dd08d5e18d5e5bbaf22039cb2fec6752e555576abb7685137a4a88f7a",
+            ),
+            (
+                u - v,
+                "0x09d95dc2adccf94793a230fdde0d0886f037d27a7bb5450698b3f428e4",
+            ),
+            (
+                v - u,
+                "0x7626a23d523306b86c5dcf0221f2fe166b81434258b9b6143d3d7f82fb",
+            ),
+            (
+                u * v,
+                "0x06e59765e66ee1e59ff1e80d6e9b85791e3c3d102cfa283523a875b1e5",
+            ),
+            (
+                u.inv(),
+                "0x3a0933c8bc2b0b838fe92ee5b1b65c94fe97c578ed3157670d6ab20c0b",
+            ),
+        ];
+        for (w, w_expected_hex_string) in test_data {
+            assert_eq!(
+                w,
+                Fn233::from_hex_string(w_expected_hex_string),
+                "Test for Fn233 arithmetic failed!"
+            );
+        }
+        assert_eq!(u / u, Fn233::one());
+        assert_eq!(u + (-u), Fn233::zero());
+    }
+
+    #[test]
+    fn test_fn_try_from() {
+        use crate::error::Error;
+        assert_eq!(Fn233::try_from("1234"), Err(Error::InvalidHexString));
+        assert_eq!(Fn233::try_from("0x"), Err(Error::InvalidHexString));
+        assert_eq!(Fn233::try_from("0x12_34"), Err(Error::InvalidHexString));
+        assert_eq!(Fn233::try_from("0x1234"), Ok(Fn233::from(0x1234)));
+        assert_eq!(Fn233::zero().try_inv(), Err(Error::NotInvertible));
+        assert_eq!(
+            Fn233::one().checked_div(Fn233::zero()),
+            Err(Error::DivisionByZero)
+        );
+    }
+}
@@ -0,0 +1,63 @@
+/// Scalar field of K-283 curve, i.e. Z / nZ where n is the order of its prime subgroup
+/// n = 0x1ffffffffffffffffffffffffffffffffffe9ae2ed07577265dff7f94451e061e163c61
+use super::{ScalarField, impl_scalar_field};
+
+// little ending u32 limbs of a canonical representative in [0, n), kept private so that every value is reduced
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Fn283([u32; 9]);
+
+impl Fn283 {
+    pub const MODULUS: [u32; 9] = [
+        504773729, 2487557638, 643694463, 785413495, 4294961582, 4294967295, 4294967295,
+        4294967295, 33554431,
+    ];
+}
+
+impl_scalar_field!(Fn283, 9, 281);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fn_arithmetic() {
+        let n = Fn283::from_hex_string(
+            "0x1ffffffffffffffffffffffffffffffffffe9ae2ed07577265dff7f94451e061e163c61",
+        );
+        assert!(n.is_zero(), "Modulus should be reduced to zero!");
+        let (u, v) = (
+            Fn283::from_hex_string(
+                "0x019589c72b8ae5d725c2eda1081491a4be9194d5075dd683f736e561d503a56381892cd5",
+            ),
+            Fn283::from_hex_string(
+                "0x0081c5720c9f2f66f87f47288a52adad1c6babf9eb091b8bed039f0dfc3d25d65312ab81",
+            ),
+        );
+        let test_data = [
+            (
+                u + v,
+                "0x00174f39382a153e1e4234c992673f51dafd5720c3967c98bddc84f03cfbad33b6859bf5",
+            ),
+            (
+                u - v,
+                "0x0113c4551eebb6702d43a6787dc1e3f7a225e8db1c54baf80a334653d8c67f8d2e768154",
+            ),
+            (
+                u * v,
+                "0x01bbc64d775dbda558cc4b9d55ee0f315220c75dbec95d94d1092b7cf62da5c010a043fd",
+            ),
+            (
+                u.inv(),
+                "0x006428718709fb3b4a413d6ea4cb6bdc1c263358c41b3e1579698b1a89c56c44907bfc6b",
+            ),
+        ];
+        for (w, w_expected_hex_string) in test_data {
+            assert_eq!(
+                w.to_hex_string(),
+                w_expected_hex_string,
+                "Test for Fn283 arithmetic failed!"
+            );
+        }
+        assert_eq!(Fn283::from_be_bytes_mod_order(&u.to_be_bytes()), u);
+    }
+}
@@ -0,0 +1,63 @@
+/// Scalar field of K-409 curve, i.e. Z / nZ where n is the order of its prime subgroup
+/// n = 0x7ffffffffffffffffffffffffffffffffffffffffffffffffffe5f83b2d4ea20400ec4557d5ed3e3e7ca5b4b5c83b8e01e5fcf
+use super::{ScalarField, impl_scalar_field};
+
+// little ending u32 limbs of a canonical representative in [0, n), kept private so that every value is reduced
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Fn409([u32; 13]);
+
+impl Fn409 {
+    pub const MODULUS: [u32; 13] = [
+        3760086991, 1264354232, 3823618651, 1434279635, 541068996, 2209535210, 4294966879,
+        4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 8388607,
+    ];
+}
+
+impl_scalar_field!(Fn409, 13, 407);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fn_arithmetic() {
+        let n = Fn409::from_hex_string(
+            "0x7ffffffffffffffffffffffffffffffffffffffffffffffffffe5f83b2d4ea20400ec4557d5ed3e3e7ca5b4b5c83b8e01e5fcf",
+        );
+        assert!(n.is_zero(), "Modulus should be reduced to zero!");
+        let (u, v) = (
+            Fn409::from_hex_string(
+                "0x2c6a7c241ce7cce2c9686608ac5dbe3e234021d41f5c3c99b2d6624768c91b78e45cc712c2a69152d8ced6b7c38adcdfa9a315",
+            ),
+            Fn409::from_hex_string(
+                "0x4ccdd99c92e64ad82d695339d4ed3723762ae54f2d3e0004a5ddb11f2140494bdfc3a3d58e5ab46d1d9b6041162eac1fa60d54",
+            ),
+        );
+        let test_data = [
+            (
+                u + v,
+                "0x793855c0afce17baf6d1b942814af561996b07234c9a3c9e58b413668a0964c4c4206ae8510145bff66a36f8d9b988ff4fb069",
+            ),
+            (
+                u - v,
+                "0x5f9ca2878a01820a9bff12ced770871aad153c84f21e3c950cf710abfa5dbc4d44a7e792b1aab0c9a2fdd1c209dfe9a021f590",
+            ),
+            (
+                u * v,
+                "0x021d3c28c9ff0efca30a8221b7628e8903ff2cc898bc8e130f0bf16ba186c30ff90b2d35f7af5bb3b552f21c0a1cfc42a1e0d2",
+            ),
+            (
+                u.inv(),
+                "0x4ef69f20c8188eb7616e0d92d25c8d2ee342525c1ef67b87a584c52d71d93edcfaec66bd1affaa0d3bef92034805f8ab254427",
+            ),
+        ];
+        for (w, w_expected_hex_string) in test_data {
+            assert_eq!(
+                w.to_hex_string(),
+                w_expected_hex_string,
+                "Test for Fn409 arithmetic failed!"
+            );
+        }
+        assert_eq!(Fn409::from_be_bytes_mod_order(&u.to_be_bytes()), u);
+    }
+}
@@ -0,0 +1,63 @@
+/// Scalar field of K-571 curve, i.e. Z / nZ where n is the order of its prime subgroup
+/// n = 0x20000000000000000000000000000000000000000000000000000000000000000000000131850e1f19a63e4b391a8db917f4138b630d84be5d639381e91deb45cfe778f637c1001
+use super::{ScalarField, impl_scalar_field};
+
+// little ending u32 limbs of a canonical representative in [0, n), kept private so that every value is reduced
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Fn571([u32; 18]);
+
+impl Fn571 {
+    pub const MODULUS: [u32; 18] = [
+        1669074945, 1560180623, 512876212, 3856021816, 3056654411, 2441036088, 3012667611,
+        4053427172, 320360673, 0, 0, 0, 0, 0, 0, 0, 0, 33554432,
+    ];
+}
+
+impl_scalar_field!(Fn571, 18, 570);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fn_arithmetic() {
+        let n = Fn571::from_hex_string(
+            "0x20000000000000000000000000000000000000000000000000000000000000000000000131850e1f19a63e4b391a8db917f4138b630d84be5d639381e91deb45cfe778f637c1001",
+        );
+        assert!(n.is_zero(), "Modulus should be reduced to zero!");
+        let (u, v) = (
+            Fn571::from_hex_string(
+                "0x01329bd1f84e99ac323af8a4d1bdbf6da48c36c73fb1b71207728ed0237ba0406dedcf05a5fd0353074b7b63a01c3145e65a9b7c17e7d2242f89c40ff1d3208dceea658d5f4b996f",
+            ),
+            Fn571::from_hex_string(
+                "0x01dfb34b41e6cddcbd2d12a72c3b2d3c432bed5579bd36439c39f064facf636d0340c36d1cbdb13f3c03e19855cc4c15c968c528d2812d00d1114770a63a9271c52dae3a3bd3eff9",
+            ),
+        );
+        let test_data = [
+            (
+                u + v,
+                "0x01124f1d3a356788ef680b4bfdf8eca9e7b8241cb96eed55a3ac7f351e4b03ad712e9272afa263b051b4f9174256d4801e441f6c343826d91ac4d248797bd44b37199c3837a37967",
+            ),
+            (
+                u - v,
+                "0x0152e886b667cbcf750de5fda582923161604971c5f480ce6b389e6b28ac3cd36aad0b989c57a2f5bce1fdaffde18e0bae71178bfb977d6f444eb5d76a2a6cd066bb2ee286f3b977",
+            ),
+            (
+                u * v,
+                "0x01ee569e69f773f881cdccd08e4a9f45b301a27ed9b8f4cf1f8985e94c5de83e36058f9af42d855277bf45b7990e0d11611faf642500933b016209323007a12314403a8ecb2052c5",
+            ),
+            (
+                u.inv(),
+                "0x005b8e039b80f6ca6b6197f8b07bcd08c99780350fd3b06516ff62b70a7af9ade4a86dde887a21224cb4ef87843edf42d1e46ac009b9a96b708b5be4dafe280dda3f7ec0216d0f90",
+            ),
+        ];
+        for (w, w_expected_hex_string) in test_data {
+            assert_eq!(
+                w.to_hex_string(),
+                w_expected_hex_string,
+                "Test for Fn571 arithmetic failed!"
+            );
+        }
+        assert_eq!(Fn571::from_be_bytes_mod_order(&u.to_be_bytes()), u);
+    }
+}
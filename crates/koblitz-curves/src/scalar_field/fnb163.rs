@@ -0,0 +1,41 @@
+/// Scalar field of B-163 curve, i.e. Z / nZ where n is the order of its prime subgroup
+/// n = 0x40000000000000000000292fe77e70c12a4234c33
+use super::{ScalarField, impl_scalar_field};
+
+// little ending u32 limbs of a canonical representative in [0, n), kept private so that every value is reduced
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FnB163([u32; 6]);
+
+impl FnB163 {
+    pub const MODULUS: [u32; 6] = [2753776691, 2011630610, 168702, 0, 0, 4];
+}
+
+impl_scalar_field!(FnB163, 6, 163);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fn_arithmetic() {
+        let n = FnB163::from_hex_string("0x40000000000000000000292fe77e70c12a4234c33");
+        assert!(n.is_zero(), "Modulus should be reduced to zero!");
+        let (u, v) = (
+            FnB163::from_hex_string("0x0161076dc3ba6ace6c0a78250fb339a4769ddcc6f8"),
+            FnB163::from_hex_string("0x03b99de255f386825473b7a490f23b2cc4b4174a67"),
+        );
+        let test_data = [
+            (u + v, "0x011aa55019adf150c07e2d36a22d8dc528add0c52c"),
+            (u - v, "0x01a7698b6dc6e44c1796c3137d38e583c48de8c8c4"),
+            (u * v, "0x01022a52bf7d7ac5574076726398e2315821d9d216"),
+            (u.inv(), "0x02d94c30aee41a929d40bcfcb81ee141a56a554481"),
+        ];
+        for (w, w_expected_hex_string) in test_data {
+            assert_eq!(
+                w.to_hex_string(),
+                w_expected_hex_string,
+                "Test for FnB163 arithmetic failed!"
+            );
+        }
+    }
+}
@@ -0,0 +1,59 @@
+/// Scalar field of B-233 curve, i.e. Z / nZ where n is the order of its prime subgroup
+/// n = 0x1000000000000000000000000000013e974e72f8a6922031d2603cfe0d7
+use super::{ScalarField, impl_scalar_field};
+
+// little ending u32 limbs of a canonical representative in [0, n), kept private so that every value is reduced
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FnB233([u32; 8]);
+
+impl FnB233 {
+    pub const MODULUS: [u32; 8] = [63955159, 570629414, 3878652521, 1304948, 0, 0, 0, 256];
+}
+
+impl_scalar_field!(FnB233, 8, 233);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fn_arithmetic() {
+        let n = FnB233::from_hex_string(
+            "0x1000000000000000000000000000013e974e72f8a6922031d2603cfe0d7",
+        );
+        assert!(n.is_zero(), "Modulus should be reduced to zero!");
+        let (u, v) = (
+            FnB233::from_hex_string(
+                "0x004415607df9e4794195021cd6ff548914ef33fb4b4fda298adee5329b4e",
+            ),
+            FnB233::from_hex_string(
+                "0x0011ff7a96319345a915feb0b634bca4537f44b00011bec2223b2638d17f",
+            ),
+        );
+        let test_data = [
+            (
+                u + v,
+                "0x005614db142b77beeaab00cd8d34112d686e78ab4b6198ebad1a0b6b6ccd",
+            ),
+            (
+                u - v,
+                "0x003215e5e7c85133987f036c20ca97e4c16fef4b4b3e1b6768a3bef9c9cf",
+            ),
+            (
+                u * v,
+                "0x001faf3ff0f2f422322aa4ffde3b34623e854843190089285fdf1f029472",
+            ),
+            (
+                u.inv(),
+                "0x0055e5bb36c835008d221650f336ecb92cc1da4dbb11ea6de0ea2bf7f8e2",
+            ),
+        ];
+        for (w, w_expected_hex_string) in test_data {
+            assert_eq!(
+                w.to_hex_string(),
+                w_expected_hex_string,
+                "Test for FnB233 arithmetic failed!"
+            );
+        }
+    }
+}
@@ -0,0 +1,62 @@
+/// Scalar field of B-283 curve, i.e. Z / nZ where n is the order of its prime subgroup
+/// n = 0x3ffffffffffffffffffffffffffffffffffef90399660fc938a90165b042a7cefadb307
+use super::{ScalarField, impl_scalar_field};
+
+// little ending u32 limbs of a canonical representative in [0, n), kept private so that every value is reduced
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FnB283([u32; 9]);
+
+impl FnB283 {
+    pub const MODULUS: [u32; 9] = [
+        4021138183, 1526999676, 2475331606, 966156540, 4294963088, 4294967295, 4294967295,
+        4294967295, 67108863,
+    ];
+}
+
+impl_scalar_field!(FnB283, 9, 282);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fn_arithmetic() {
+        let n = FnB283::from_hex_string(
+            "0x3ffffffffffffffffffffffffffffffffffef90399660fc938a90165b042a7cefadb307",
+        );
+        assert!(n.is_zero(), "Modulus should be reduced to zero!");
+        let (u, v) = (
+            FnB283::from_hex_string(
+                "0x025628c0e1b7a662dd3177811c26b9c058e1fa75de0c057f163488cbb00e0067c64dd2e1",
+            ),
+            FnB283::from_hex_string(
+                "0x02fb9899930e3b99f268403afc6f313e32e2bbbd66f4a21535c490fa738865ad5d95e3eb",
+            ),
+        );
+        let test_data = [
+            (
+                u + v,
+                "0x0151c15a74c5e1fccf99b7bc1895eafe8bc4c6a30b6a4697b86e89afc8923b98343603c5",
+            ),
+            (
+                u - v,
+                "0x035a90274ea96ac8eac937461fb7888225ff2e48b0adc46673fa87e79789c5375865a1fd",
+            ),
+            (
+                u * v,
+                "0x011c5d02927c09976a06abe5b4668492aa179f06876b449d1de12f379ea8aa014b714c64",
+            ),
+            (
+                u.inv(),
+                "0x02c7282a6462b1b187469bece16b8378224e47503ee4f5d43d24c265dacbbf2f7ad28dc4",
+            ),
+        ];
+        for (w, w_expected_hex_string) in test_data {
+            assert_eq!(
+                w.to_hex_string(),
+                w_expected_hex_string,
+                "Test for FnB283 arithmetic failed!"
+            );
+        }
+    }
+}
@@ -0,0 +1,62 @@
+/// Scalar field of B-409 curve, i.e. Z / nZ where n is the order of its prime subgroup
+/// n = 0x10000000000000000000000000000000000000000000000000001e2aad6a612f33307be5fa47c3c9e052f838164cd37d9a21173
+use super::{ScalarField, impl_scalar_field};
+
+// little ending u32 limbs of a canonical representative in [0, n), kept private so that every value is reduced
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FnB409([u32; 13]);
+
+impl FnB409 {
+    pub const MODULUS: [u32; 13] = [
+        3651277171, 2170866999, 2651139971, 1604615228, 4080207806, 2866193938, 482, 0, 0, 0, 0, 0,
+        16777216,
+    ];
+}
+
+impl_scalar_field!(FnB409, 13, 409);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fn_arithmetic() {
+        let n = FnB409::from_hex_string(
+            "0x10000000000000000000000000000000000000000000000000001e2aad6a612f33307be5fa47c3c9e052f838164cd37d9a21173",
+        );
+        assert!(n.is_zero(), "Modulus should be reduced to zero!");
+        let (u, v) = (
+            FnB409::from_hex_string(
+                "0x0094a1ddaae1a1d5239e666a5e8e60308f7a8c30dc228135f4b2021d5f7aecc48287503e66a1a567c2bfcd84f6cd9abc1884b5ff",
+            ),
+            FnB409::from_hex_string(
+                "0x0096a67a638d8d11c95d3d6a3566ee58734fe4d10f97d8260c7fe292030a390425f841219b475cb61ec98244fc7561aa2a09f663",
+            ),
+        );
+        let test_data = [
+            (
+                u + v,
+                "0x002b48580e6f2ee6ecfba3d493f54e8902ca7101ebba595c0131e2ccb7ae7fb5b54c89a1a24485e14384204671de2f2e68ec9aef",
+            ),
+            (
+                u - v,
+                "0x00fdfb63475414c35a412900292771d81c2aa75fcc8aa90fe832216e074759d34fc216db2afec4ee41fb7ac37bbd0649c81cd10f",
+            ),
+            (
+                u * v,
+                "0x00ea4933e0bec9ba514ddec8da5d8782404639e76735c62dfca3cb128cb5e611b29e87b2b04fc64d034abb48d3376815fae32319",
+            ),
+            (
+                u.inv(),
+                "0x00efe8b25eb82814ba6ab097a84e21663e534929d513b5e500e5e3e85f3e6588d8816b79393d1c69a932fc84868ba050f6c7113d",
+            ),
+        ];
+        for (w, w_expected_hex_string) in test_data {
+            assert_eq!(
+                w.to_hex_string(),
+                w_expected_hex_string,
+                "Test for FnB409 arithmetic failed!"
+            );
+        }
+    }
+}
@@ -0,0 +1,63 @@
+/// Scalar field of B-571 curve, i.e. Z / nZ where n is the order of its prime subgroup
+/// n = 0x3ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe661ce18ff55987308059b186823851ec7dd9ca1161de93d5174d66e8382e9bb2fe84e47
+use super::{ScalarField, impl_scalar_field};
+
+// little ending u32 limbs of a canonical representative in [0, n), kept private so that every value is reduced
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FnB571([u32; 18]);
+
+impl FnB571 {
+    pub const MODULUS: [u32; 18] = [
+        803753543, 2206394811, 1366611566, 371059005, 3353189537, 1747158302, 134585112,
+        4283799667, 3865169432, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
+        4294967295, 4294967295, 4294967295, 67108863,
+    ];
+}
+
+impl_scalar_field!(FnB571, 18, 570);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fn_arithmetic() {
+        let n = FnB571::from_hex_string(
+            "0x3ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe661ce18ff55987308059b186823851ec7dd9ca1161de93d5174d66e8382e9bb2fe84e47",
+        );
+        assert!(n.is_zero(), "Modulus should be reduced to zero!");
+        let (u, v) = (
+            FnB571::from_hex_string(
+                "0x032899a2c7b7d938298791551592de82d20477aca132f3f0cc64adb82c5f44d720db29c48761e88346dfba810c67d997ec5621101966e7b4594f9b0c7e9358719bc55eb7e1587069",
+            ),
+            FnB571::from_hex_string(
+                "0x010cbcc1f249b5ce97ca5e0e7b6864928c7254612c4cfd20154c5eb5636694ba8ba64bc38f2ab367976e108774ea20b7794032f52d048a8833f0de1e0354e640dbee95d538efb8d1",
+            ),
+        );
+        let test_data = [
+            (
+                u + v,
+                "0x00355664ba018f06c151ef6390fb43155e76cc0dcd7ff110e1b10c6d8fc5d991ac817588302acdd1def83295794c5f36fd72cee67e8dd59b77228fed30736843f4310ad1ea5fdaf3",
+            ),
+            (
+                u - v,
+                "0x021bdce0d56e236991bd33469a2a79f04592234b74e5f6d0b7184f02c8f8b01c9534de00f837351baf71a9f9977db8e07315ee1aec625d2c255ebcee7b3e7230bfd6c8e2a868b798",
+            ),
+            (
+                u * v,
+                "0x035f66290419521cc3136440bb1566b7ea90a4e57198d72d80b29cf145a5d233af7a10158b3d8a89e08edd50bd5a4c7d87f1432ca52fe9aab62e1eb670a9a0929a1922f783bad940",
+            ),
+            (
+                u.inv(),
+                "0x03a414a6eb7332b663b990be58fa4cd5cdc72931ad1ad350b4de0a630d4652dd436251459ba2d7211492423e6b9eab24bba13391674e2091ffe9a6ba2a9fe001d40ffcfa91577f40",
+            ),
+        ];
+        for (w, w_expected_hex_string) in test_data {
+            assert_eq!(
+                w.to_hex_string(),
+                w_expected_hex_string,
+                "Test for FnB571 arithmetic failed!"
+            );
+        }
+    }
+}
@@ -0,0 +1,227 @@
+pub mod fn163;
+pub mod fn233;
+pub mod fn283;
+pub mod fn409;
+pub mod fn571;
+pub mod fnb163;
+pub mod fnb233;
+pub mod fnb283;
+pub mod fnb409;
+pub mod fnb571;
+
+use crate::error::Error;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+// Prime field Z / nZ where n is the order of the prime subgroup of a binary curve,
+// scalars of points and signatures live here rather than in the binary field
+pub trait ScalarField:
+    Debug
+    + Eq
+    + PartialEq
+    + Copy
+    + Clone
+    + Add<Self, Output = Self>
+    + Sub<Self, Output = Self>
+    + Mul<Self, Output = Self>
+    + Div<Self, Output = Self>
+    + Neg<Output = Self>
+{
+    // bit length of the modulus n
+    const BITS: usize;
+    // the modulus n
+    fn modulus() -> BigUint;
+    // reduce a non-negative integer modulo n
+    fn from_biguint(v: &BigUint) -> Self;
+    // canonical representative in [0, n)
+    fn to_biguint(&self) -> BigUint;
+
+    fn zero() -> Self {
+        Self::from_biguint(&BigUint::zero())
+    }
+
+    fn one() -> Self {
+        Self::from_biguint(&BigUint::one())
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    fn is_one(&self) -> bool {
+        *self == Self::one()
+    }
+
+    // panicking counterpart of try_inv
+    fn inv(&self) -> Self {
+        self.try_inv().expect("Zero can not be inversed!")
+    }
+
+    // inverse by Fermat's little theorem, a^{-1} = a^{n - 2} (mod n)
+    fn try_inv(&self) -> Result<Self, Error> {
+        if self.is_zero() {
+            return Err(Error::NotInvertible);
+        }
+        let n = Self::modulus();
+        Ok(Self::from_biguint(
+            &self.to_biguint().modpow(&(&n - 2u32), &n),
+        ))
+    }
+
+    // division which fails on zero denominator instead of panicking
+    fn checked_div(self, rhs: Self) -> Result<Self, Error> {
+        let rhs_inv = rhs.try_inv().map_err(|_| Error::DivisionByZero)?;
+        Ok(self * rhs_inv)
+    }
+
+    // reduce big ending bytes of any length modulo n
+    fn from_be_bytes_mod_order(bytes: &[u8]) -> Self {
+        Self::from_biguint(&BigUint::from_bytes_be(bytes))
+    }
+
+    // big ending bytes with fixed length (BITS + 7) / 8
+    fn to_be_bytes(&self) -> Vec<u8> {
+        let bytes = self.to_biguint().to_bytes_be();
+        let mut result = vec![0u8; Self::BITS.div_ceil(8)];
+        let offset = result.len() - bytes.len();
+        result[offset..].copy_from_slice(&bytes);
+        result
+    }
+
+    // the i-th bit of the canonical representative, i < BITS
+    fn bit(&self, i: usize) -> u8 {
+        self.to_biguint().bit(i as u64) as u8
+    }
+
+    // convert to little ending bits and remove leading zeros if necessary
+    fn bits(&self, remove: bool) -> Vec<u8> {
+        let v = self.to_biguint();
+        let l = if remove {
+            v.bits() as usize
+        } else {
+            Self::BITS
+        };
+        (0..l as u64).map(|i| v.bit(i) as u8).collect::<Vec<_>>()
+    }
+}
+
+// implement hex conversions, ScalarField and arithmetic operators for a scalar field
+// which is a newtype over private little ending u32 limbs and provides the limbs of its modulus as MODULUS,
+// every constructor reduces modulo n so that bit() and equality only see canonical representatives
+macro_rules! impl_scalar_field {
+    ($Field:ident, $LIMBS:expr, $BITS:expr) => {
+        impl $Field {
+            // initiate from big ending hex string, reduced modulo n, panics on malformed input
+            pub fn from_hex_string(s: &str) -> Self {
+                Self::try_from_hex_string(s).expect("Invalid hex string")
+            }
+
+            // initiate from big ending hex string which must start with "0x", reduced modulo n
+            pub fn try_from_hex_string(s: &str) -> Result<Self, $crate::error::Error> {
+                use $crate::error::Error;
+                let hex_string = s.strip_prefix("0x").ok_or(Error::InvalidHexString)?;
+                if hex_string.is_empty() || !hex_string.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return Err(Error::InvalidHexString);
+                }
+                let v = num_bigint::BigUint::parse_bytes(hex_string.as_bytes(), 16)
+                    .ok_or(Error::InvalidHexString)?;
+                Ok(Self::from_biguint(&v))
+            }
+
+            // convert to big ending hex string
+            pub fn to_hex_string(&self) -> String {
+                format!("0x{}", hex::encode(self.to_be_bytes()))
+            }
+        }
+
+        impl TryFrom<&str> for $Field {
+            type Error = $crate::error::Error;
+
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                Self::try_from_hex_string(s)
+            }
+        }
+
+        impl From<u64> for $Field {
+            fn from(v: u64) -> Self {
+                Self::from_biguint(&num_bigint::BigUint::from(v))
+            }
+        }
+
+        impl $crate::scalar_field::ScalarField for $Field {
+            const BITS: usize = $BITS;
+
+            fn modulus() -> num_bigint::BigUint {
+                num_bigint::BigUint::from_slice(&Self::MODULUS)
+            }
+
+            fn from_biguint(v: &num_bigint::BigUint) -> Self {
+                let mut limbs = [0u32; $LIMBS];
+                let digits = (v % Self::modulus()).to_u32_digits();
+                limbs[..digits.len()].copy_from_slice(&digits);
+                Self(limbs)
+            }
+
+            fn to_biguint(&self) -> num_bigint::BigUint {
+                num_bigint::BigUint::from_slice(&self.0)
+            }
+
+            // fold all the limbs instead of comparing them one by one, since secret scalars are checked against zero
+            fn is_zero(&self) -> bool {
+                self.0.iter().fold(0, |acc, w| acc | w) == 0
+            }
+
+            // read from the fixed-width limbs, so that the ladder never goes through a variable-length integer
+            fn bit(&self, i: usize) -> u8 {
+                ((self.0[i / 32] >> (i % 32)) & 1) as u8
+            }
+        }
+
+        impl core::ops::Add for $Field {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self::from_biguint(&(self.to_biguint() + rhs.to_biguint()))
+            }
+        }
+
+        impl core::ops::Sub for $Field {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                self + (-rhs)
+            }
+        }
+
+        impl core::ops::Neg for $Field {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                if self.is_zero() {
+                    return self;
+                }
+                Self::from_biguint(&(Self::modulus() - self.to_biguint()))
+            }
+        }
+
+        impl core::ops::Mul for $Field {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                Self::from_biguint(&(self.to_biguint() * rhs.to_biguint()))
+            }
+        }
+
+        impl core::ops::Div for $Field {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                self.checked_div(rhs)
+                    .expect("Denominator should not be zero!")
+            }
+        }
+    };
+}
+pub(crate) use impl_scalar_field;